@@ -0,0 +1,97 @@
+use crate::{ScaledValue, SpecialBytes};
+
+// The errors are relative to the original number and given in percent.
+// They are calculated from the absolute values in `u128`, so no intermediate value has to fit in `T`.
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Returns the relative error of the approximate value for the original number in percent:
+    /// `|original - approximate| / |original| * 100`.
+    ///
+    /// The error is `0.0` if both numbers are zero and infinite if only the original number is zero.
+    /// ### Example
+    /// ```rust
+    /// let small_value = SmallValue::new(1000u32);
+    /// let error = small_value.error_rate(1000); // 1%, the approximation is 990
+    /// ```
+    pub fn error_rate(&self, original: T) -> f64 {
+        let approx = self.approximate();
+        let (original_abs, approx_abs) = (Self::unsigned_abs(original), Self::unsigned_abs(approx));
+        let difference = if (original < T::zero()) == (approx < T::zero()) {
+            original_abs.abs_diff(approx_abs)
+        } else {
+            original_abs.saturating_add(approx_abs)
+        };
+
+        match (difference, original_abs) {
+            (0, _) => 0.0,
+            (_, 0) => f64::INFINITY,
+            _ => difference as f64 / original_abs as f64 * 100.0,
+        }
+    }
+
+    /// Returns the largest `error_rate` of the numbers that `new` maps to this value (see `bucket`).
+    /// For a value that `new` never produces the numbers between `bounds` are used.
+    pub fn max_relative_error(&self) -> f64 {
+        // the error only grows with the distance from the approximation, so the worst case is at an end
+        let interval = self.interval();
+        self.error_rate(interval.min())
+            .max(self.error_rate(interval.max()))
+    }
+
+    /// Returns the largest `error_rate` of `new` for the numbers whose absolute value is at least `SCALE`
+    /// (not for the whole type), `None` if `T` has no such numbers.
+    ///
+    /// Smaller numbers have fewer numbers per bit length than steps and are not included:
+    /// `new(1)` approximates to `0`, so the error over all numbers is always `100.0`.
+    /// `worst_case_relative_error_from` gives the bound for any threshold.
+    /// ### Example
+    /// ```rust
+    /// let error = SmallValue::<u32>::worst_case_relative_error(); // Some(about 22%), from the numbers below 256
+    /// assert_eq!(ScaledValue::<i8, 255>::worst_case_relative_error(), None);
+    /// ```
+    pub fn worst_case_relative_error() -> Option<f64> {
+        let scale = u128::from(SCALE);
+        let largest = Self::unsigned_abs(T::min_value()).max(Self::unsigned_abs(T::max_value()));
+        (largest >= scale).then(|| Self::worst_error(scale))
+    }
+
+    /// Returns the largest `error_rate` of `new` for the numbers whose absolute value is at least `|min|`.
+    /// ### Example
+    /// ```rust
+    /// // every number from 10_000 on is stored with an error of less than 2%
+    /// assert!(SmallValue::<u64>::worst_case_relative_error_from(10_000) < 2.0);
+    /// ```
+    pub fn worst_case_relative_error_from(min: T) -> f64 {
+        Self::worst_error(Self::unsigned_abs(min))
+    }
+
+    // For the numbers of one bit length the approximation changes only after a representable number,
+    // so the error is the largest at such a number, at the end of the bit length,
+    // or at its start (when the approximation is larger than the number).
+    fn worst_error(from: u128) -> f64 {
+        let signs: &[bool] = if Self::is_signed() {
+            &[false, true]
+        } else {
+            &[false]
+        };
+
+        let mut worst = 0.0f64;
+        for &flag in signs {
+            for min_bits in Self::min_bits_range() {
+                let magnitudes = Self::magnitude_range(min_bits, flag);
+                let (low, high) = ((*magnitudes.start()).max(from), *magnitudes.end());
+                if low > high {
+                    continue;
+                }
+
+                let representable = Self::percent_range()
+                    .filter_map(|percent| Self::magnitude(min_bits, percent))
+                    .filter(|magnitude| (low..=high).contains(magnitude));
+                for magnitude in [low, high].into_iter().chain(representable) {
+                    let number = Self::from_magnitude(magnitude, flag);
+                    worst = worst.max(Self::new(number).error_rate(number));
+                }
+            }
+        }
+        worst
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{ScaledValue, SpecialBytes};
+use core::ops::RangeInclusive;
+
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Returns the exact range of the original numbers that `new` maps to this value.
+    ///
+    /// Unlike `bounds`, every number `x` in the range satisfies `SmallValue::new(x) == self`,
+    /// and there are no other such numbers. The range ends at `T::MAX` (`T::MIN`)
+    /// for the last bucket of the type. The range is empty if `new` never produces this value.
+    /// ### Example
+    /// ```rust
+    /// let small_value = SmallValue::new(1000u32);
+    /// let bucket = small_value.bucket();
+    /// assert!(bucket.contains(&1000));
+    /// ```
+    pub fn bucket(&self) -> RangeInclusive<T> {
+        if !(self.is_valid() && self.is_canonical()) {
+            return T::one()..=T::zero();
+        }
+
+        match self.magnitudes(self.flag) {
+            Some((start, end)) if self.flag => {
+                Self::from_magnitude(end, true)..=Self::from_magnitude(start, true)
+            }
+            // zero also takes the small negative numbers
+            Some((0, end)) if Self::is_signed() => {
+                let negative = self.magnitudes(true).map_or(0, |(_, end)| end);
+                Self::from_magnitude(negative, true)..=Self::from_magnitude(end, false)
+            }
+            Some((start, end)) => {
+                Self::from_magnitude(start, false)..=Self::from_magnitude(end, false)
+            }
+            None => T::one()..=T::zero(),
+        }
+    }
+
+    /// Checks whether `new` maps the number to this value (the number is in `bucket`).
+    pub fn contains(&self, number: T) -> bool {
+        Self::new(number) == *self
+    }
+
+    // The absolute values with the sign `flag` that `new` maps to the value, they can have any bit length
+    // because `normalize` moves the result of `encode` to the shortest one.
+    fn magnitudes(&self, flag: bool) -> Option<(u128, u128)> {
+        let low = u128::from(flag);
+        let high = Self::unsigned_abs(if flag { T::min_value() } else { T::max_value() });
+
+        // `new` never decreases the approximation when the absolute value grows
+        let target = Self::magnitude(self.min_bits, self.percent);
+        let approx = |magnitude| {
+            let small_value = Self::encode(Self::from_magnitude(magnitude, flag));
+            Self::magnitude(small_value.min_bits, small_value.percent)
+        };
+        let start = match Self::last_matching(low, high, |magnitude| approx(magnitude) < target) {
+            Some(before) if before == high => None,
+            Some(before) => Some(before + 1),
+            None => Some(low),
+        };
+        let end = Self::last_matching(low, high, |magnitude| approx(magnitude) <= target);
+
+        match (start, end) {
+            (Some(start), Some(end)) if start <= end => Some((start, end)),
+            _ => None,
+        }
+    }
+
+    // The last absolute value in `low..=high` for which `predicate` is true,
+    // `predicate` must be true only at the beginning of the range.
+    fn last_matching(low: u128, high: u128, predicate: impl Fn(u128) -> bool) -> Option<u128> {
+        if low > high || !predicate(low) {
+            return None;
+        }
+
+        let (mut low, mut high) = (low, high);
+        while low < high {
+            // rounded up, so the loop always moves forward
+            let middle = low + (high - low) / 2 + (high - low) % 2;
+            if predicate(middle) {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        Some(low)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{Rounding, ScaledValue, SpecialBytes};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Returns the canonical encoding of the same approximate value.
+    ///
+    /// Several raw tuples can decode to the same number (e.g. every percentage of `1` bit gives zero),
+    /// the canonical one has the smallest number of bits and then the smallest percentage.
+    /// The sign is kept except for zero, so small negative numbers approximate to the same zero as `0`.
+    ///
+    /// `new`, `new_with`, `try_from` and the arithmetic always return canonical values,
+    /// only `from_raw_unchecked` can create other ones. Raw parts that are not valid
+    /// are replaced with the closest valid value.
+    /// ### Example
+    /// ```rust
+    /// let small_value = SmallValue::<u32>::from_raw_unchecked(1, 99, false);
+    /// assert_eq!(small_value.normalize(), SmallValue::new(0));
+    /// ```
+    pub fn normalize(&self) -> Self {
+        let magnitude = Self::magnitude(self.min_bits, self.percent).unwrap_or_default();
+        let flag = self.flag && Self::is_signed() && magnitude != 0;
+
+        for min_bits in Self::bits_of(magnitude)..=*Self::min_bits_range().end() {
+            let percent = Self::first_at_least(min_bits, magnitude)
+                .filter(|percent| Self::magnitude(min_bits, *percent) == Some(magnitude));
+            if let Some(percent) = percent {
+                return Self::from_raw_unchecked(min_bits, percent, flag);
+            }
+        }
+
+        // a percentage out of range can give a number that no valid encoding has
+        Self::new_with(Self::from_magnitude(magnitude, flag), Rounding::Nearest)
+    }
+
+    /// Checks that the value is already in the canonical form (see `normalize`).
+    pub fn is_canonical(&self) -> bool {
+        self.is_canonical_fast() || {
+            let canonical = self.normalize();
+            (self.min_bits, self.percent, self.flag)
+                == (canonical.min_bits, canonical.percent, canonical.flag)
+        }
+    }
+
+    // A check without the search over the bit lengths, it can only miss canonical values
+    // whose absolute value is below the bit length (shorter bit lengths have to be searched for them).
+    fn is_canonical_fast(&self) -> bool {
+        let Some(magnitude) = Self::magnitude(self.min_bits, self.percent) else {
+            return false;
+        };
+        let first = *Self::percent_range().start();
+
+        self.is_valid()
+            && !(self.flag && magnitude == 0)
+            && Self::bits_of(magnitude) == self.min_bits
+            && (self.percent == first
+                || Self::magnitude(self.min_bits, self.percent - 1) != Some(magnitude))
+    }
+
+    // The canonical raw parts that `Eq` and `Hash` use, the values of `new` are already canonical.
+    fn canonical_parts(&self) -> (u8, u8, bool) {
+        let canonical = if self.is_canonical_fast() {
+            *self
+        } else {
+            self.normalize()
+        };
+        (canonical.min_bits, canonical.percent, canonical.flag)
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> PartialEq for ScaledValue<T, SCALE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical_parts() == other.canonical_parts()
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> Eq for ScaledValue<T, SCALE> {}
+
+impl<T: SpecialBytes, const SCALE: u8> Hash for ScaledValue<T, SCALE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_parts().hash(state);
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> PartialOrd for ScaledValue<T, SCALE> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Values are ordered by `approximate`.
+impl<T: SpecialBytes, const SCALE: u8> Ord for ScaledValue<T, SCALE> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // every valid encoding has the approximation of its canonical value
+        let approximate = |value: &Self| {
+            if value.is_valid() {
+                value.approximate()
+            } else {
+                value.normalize().approximate()
+            }
+        };
+        approximate(self).cmp(&approximate(other))
+    }
+}
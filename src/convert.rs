@@ -0,0 +1,58 @@
+use crate::{ScaledValue, SmallValueError};
+
+// Every bit length of a narrower type gives the same number in a wider type,
+// so the conversions keep the raw parts and `approximate` does not change.
+macro_rules! impl_widening {
+    ($($from:ty => [$($to:ty),*]);* $(;)?) => {
+        $($(
+            impl<const SCALE: u8> From<ScaledValue<$from, SCALE>> for ScaledValue<$to, SCALE> {
+                fn from(value: ScaledValue<$from, SCALE>) -> Self {
+                    Self::from_raw_unchecked(value.min_bits, value.percent, value.flag)
+                }
+            }
+        )*)*
+    };
+}
+
+// The conversion fails if the number of bits or the sign is not available in the target type,
+// otherwise `approximate` does not change.
+macro_rules! impl_narrowing {
+    ($($from:ty => [$($to:ty),*]);* $(;)?) => {
+        $($(
+            impl<const SCALE: u8> TryFrom<ScaledValue<$from, SCALE>> for ScaledValue<$to, SCALE> {
+                type Error = SmallValueError;
+
+                fn try_from(value: ScaledValue<$from, SCALE>) -> Result<Self, Self::Error> {
+                    Self::try_from((value.min_bits, value.percent, value.flag))
+                }
+            }
+        )*)*
+    };
+}
+
+impl_widening! {
+    u8 => [u16, u32, u64, u128, i16, i32, i64, i128];
+    u16 => [u32, u64, u128, i32, i64, i128];
+    u32 => [u64, u128, i64, i128];
+    u64 => [u128, i128];
+    i8 => [i16, i32, i64, i128];
+    i16 => [i32, i64, i128];
+    i32 => [i64, i128];
+    i64 => [i128];
+}
+
+// `usize` and `isize` depend on the platform, so they are always checked.
+impl_narrowing! {
+    u8 => [i8, usize, isize];
+    u16 => [u8, i8, i16, usize, isize];
+    u32 => [u8, u16, i8, i16, i32, usize, isize];
+    u64 => [u8, u16, u32, i8, i16, i32, i64, usize, isize];
+    u128 => [u8, u16, u32, u64, i8, i16, i32, i64, i128, usize, isize];
+    usize => [u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize];
+    i8 => [u8, u16, u32, u64, u128, usize, isize];
+    i16 => [u8, u16, u32, u64, u128, i8, usize, isize];
+    i32 => [u8, u16, u32, u64, u128, i8, i16, usize, isize];
+    i64 => [u8, u16, u32, u64, u128, i8, i16, i32, usize, isize];
+    i128 => [u8, u16, u32, u64, u128, i8, i16, i32, i64, usize, isize];
+    isize => [u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, usize];
+}
@@ -0,0 +1,211 @@
+use crate::{ApproxInterval, ScaledValue, SpecialBytes};
+
+/// A probabilistic counter (like the Morris counter) stored in a single `ScaledValue`.
+///
+/// Adding `1` to a wide bucket and encoding the result again never moves the value forward,
+/// so the counter instead moves to the next representable value with the probability `1 / step`.
+/// The expected value of `estimate` is the exact count, and the state still takes 16 bits (`to_bits`).
+///
+/// The random numbers come from the caller: any source of uniformly distributed `u64` values.
+/// ### Example
+/// ```rust
+/// let mut state = 0x2545_F491_4F6C_DD1Du64;
+/// let mut rng = || {
+///     // xorshift
+///     state ^= state << 13;
+///     state ^= state >> 7;
+///     state ^= state << 17;
+///     state
+/// };
+///
+/// let mut counter = ApproxCounter::<u64>::new();
+/// for _ in 0..1_000_000 {
+///     counter.increment(&mut rng);
+/// }
+/// let count = counter.estimate(); // about 1_000_000
+/// let range = counter.confidence_interval(2); // about 95%
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ApproxCounter<T: SpecialBytes, const SCALE: u8 = 100> {
+    value: ScaledValue<T, SCALE>,
+}
+
+impl<T: SpecialBytes, const SCALE: u8> ApproxCounter<T, SCALE> {
+    /// Creates a counter at zero.
+    pub fn new() -> Self {
+        Self::from_value(ScaledValue::new(T::zero()))
+    }
+
+    /// Creates a counter from its state (see `value`), a negative value is taken as zero.
+    pub fn from_value(value: ScaledValue<T, SCALE>) -> Self {
+        let value = if value.approximate() < T::zero() {
+            ScaledValue::new(T::zero())
+        } else {
+            value.normalize()
+        };
+        Self { value }
+    }
+
+    /// Returns the state of the counter, e.g. to store it with `to_bits`.
+    pub fn value(&self) -> ScaledValue<T, SCALE> {
+        self.value
+    }
+
+    /// Returns the estimated count, the approximate value of the state.
+    pub fn estimate(&self) -> T {
+        self.value.approximate()
+    }
+
+    /// Adds one to the counter.
+    pub fn increment(&mut self, rng: impl FnMut() -> u64) {
+        self.increment_by(T::one(), rng);
+    }
+
+    /// Adds `n` to the counter with a single random number.
+    ///
+    /// The counter moves to one of the two representable values around `estimate() + n`,
+    /// with the probabilities that keep the expected value exact.
+    /// The counter stops at the largest representable value of `T`, a negative `n` is ignored.
+    pub fn increment_by(&mut self, n: T, mut rng: impl FnMut() -> u64) {
+        if n <= T::zero() {
+            return;
+        }
+
+        let current = Self::magnitude(self.estimate());
+        let target = current.saturating_add(Self::magnitude(n));
+        let next = self
+            .next_in_bit_length(current)
+            .or_else(|| ScaledValue::<T, SCALE>::next_magnitude(current));
+        // usually the target is before the next step, then the counter stays or moves to it
+        let (low, high) = match next {
+            Some((_, _, high)) if target < high => {
+                ((self.value.min_bits(), self.value.percent(), current), next)
+            }
+            _ => {
+                let low = ScaledValue::<T, SCALE>::prev_magnitude(target);
+                (low, ScaledValue::<T, SCALE>::next_magnitude(low.2))
+            }
+        };
+
+        let (min_bits, percent, magnitude) = match high {
+            Some(high)
+                if low.2 < target && Self::chance(target - low.2, high.2 - low.2, &mut rng) =>
+            {
+                high
+            }
+            _ => low,
+        };
+        if magnitude != current {
+            self.value = ScaledValue::from_raw_unchecked(min_bits, percent, false).normalize();
+        }
+    }
+
+    /// Adds the count of the other counter, the result is an estimate of the sum of both counts.
+    pub fn merge(&mut self, other: &Self, rng: impl FnMut() -> u64) {
+        self.increment_by(other.estimate(), rng);
+    }
+
+    /// Returns the range `estimate() ± deviations * σ`, clamped to `0..=T::MAX`.
+    ///
+    /// `σ` is the standard deviation of a counter that was incremented one at a time up to the estimate
+    /// (`increment_by` and `merge` add less uncertainty). For large counts the estimate is about normally
+    /// distributed, so `1`, `2` and `3` deviations contain the exact count in about 68%, 95% and 99.7% of cases.
+    pub fn confidence_interval(&self, deviations: u32) -> ApproxInterval<T> {
+        let estimate = Self::magnitude(self.estimate());
+        let spread = self
+            .variance()
+            .isqrt()
+            .saturating_mul(u128::from(deviations));
+        let max = Self::magnitude(T::max_value());
+
+        ApproxInterval::new(
+            Self::number(estimate.saturating_sub(spread)),
+            Self::number(estimate.saturating_add(spread).min(max)),
+        )
+    }
+
+    // The next representable value after `current` when it is the next percentage of the same bit length
+    // or a value of a longer bit length before it, `None` if the full search is needed.
+    // The steps of the longer bit lengths are at least twice as wide, so each has at most one value in between.
+    fn next_in_bit_length(&self, current: u128) -> Option<(u8, u8, u128)> {
+        let (min_bits, percent) = (self.value.min_bits(), self.value.percent());
+        let scale = u128::from(SCALE);
+        let last = *ScaledValue::<T, SCALE>::min_bits_range().end();
+        let total = *ScaledValue::<T, SCALE>::magnitude_range(min_bits, false).end();
+
+        // shorter bit lengths have only smaller values, `total / SCALE` is the step of the bit length
+        if ScaledValue::<T, SCALE>::bits_of(current) != min_bits
+            || total < scale
+            || percent >= *ScaledValue::<T, SCALE>::percent_range().end()
+        {
+            return None;
+        }
+
+        let (mut step, mut rest) = (total / scale, total % scale);
+        let mut next = (min_bits, percent + 1, step * u128::from(percent + 1));
+        for longer in min_bits + 1..=last {
+            // the total of the next bit length is `2 * total + 1`
+            step = 2 * step + (2 * rest + 1) / scale;
+            rest = (2 * rest + 1) % scale;
+            if step >= next.2 {
+                break;
+            }
+
+            let steps = Self::quotient(current, step) + 1;
+            if steps * step < next.2 {
+                next = (longer, steps as u8, steps * step);
+            }
+        }
+        Some(next)
+    }
+
+    // Every increment inside a step of the width `w` moves the counter by `w` with the probability `1 / w`,
+    // which adds the variance `w - 1`, and it takes about `w` increments to cross the step.
+    fn variance(&self) -> u128 {
+        let estimate = Self::magnitude(self.estimate());
+        let mut variance = 0u128;
+        let mut magnitude = 0u128;
+
+        while magnitude < estimate {
+            let Some((_, _, next)) = ScaledValue::<T, SCALE>::next_magnitude(magnitude) else {
+                break;
+            };
+            let width = next - magnitude;
+            variance = variance.saturating_add(width.saturating_mul(width - 1));
+            magnitude = next;
+        }
+        variance
+    }
+
+    // The division in `u64` when the numbers fit, it is much faster than in `u128`.
+    fn quotient(dividend: u128, divisor: u128) -> u128 {
+        match (u64::try_from(dividend), u64::try_from(divisor)) {
+            (Ok(dividend), Ok(divisor)) => u128::from(dividend / divisor),
+            _ => dividend / divisor,
+        }
+    }
+
+    // `true` with the probability `numerator / denominator` (`numerator < denominator`).
+    fn chance(numerator: u128, denominator: u128, rng: &mut impl FnMut() -> u64) -> bool {
+        // only the highest 64 bits of the denominator are used
+        let shift = (128 - denominator.leading_zeros()).saturating_sub(64);
+        let (numerator, denominator) = (numerator >> shift, denominator >> shift);
+        // `rng() / 2^64 < numerator / denominator` without a division
+        u128::from(rng()) * denominator < numerator << 64
+    }
+
+    // The counts are never negative.
+    fn magnitude(number: T) -> u128 {
+        number.to_u128().unwrap_or_default()
+    }
+
+    fn number(magnitude: u128) -> T {
+        T::from(magnitude).unwrap_or_else(T::max_value)
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> Default for ApproxCounter<T, SCALE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
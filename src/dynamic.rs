@@ -0,0 +1,287 @@
+use crate::{ScaledValue, SmallValueError, SpecialBytes};
+use core::fmt;
+
+/// The integer type that a `DynScaledValue` was encoded from.
+///
+/// `usize` and `isize` depend on the platform, so they are stored as the type of the same width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IntKind {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+}
+
+impl IntKind {
+    const ALL: [Self; 10] = [
+        Self::U8,
+        Self::U16,
+        Self::U32,
+        Self::U64,
+        Self::U128,
+        Self::I8,
+        Self::I16,
+        Self::I32,
+        Self::I64,
+        Self::I128,
+    ];
+
+    /// Returns the name of the type, e.g. `"u32"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::U128 => "u128",
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::I128 => "i128",
+        }
+    }
+
+    /// Finds the type by its name (see `name`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+
+    /// Returns a stable code of the type for binary formats.
+    pub fn code(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Finds the type by its code (see `code`).
+    pub fn from_code(code: u8) -> Option<Self> {
+        Self::ALL.get(usize::from(code)).copied()
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl fmt::Display for IntKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A `ScaledValue` of any integer type: the raw parts are stored together with the type (`IntKind`),
+/// so values of different types can be kept in one collection and decoded without knowing `T`.
+///
+/// `DynSmallValue` is the same for `SmallValue` (`SCALE = 100`).
+/// ### Example
+/// ```rust
+/// let column: Vec<DynSmallValue> = vec![
+///     SmallValue::new(1000u32).into(),
+///     SmallValue::new(-5000i64).into(),
+/// ];
+/// assert_eq!(column[1].to_i128(), Some(-4941));
+/// let value: SmallValue<u32> = column[0].try_into()?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynScaledValue<const SCALE: u8> {
+    kind: IntKind,
+    min_bits: u8,
+    percent: u8,
+    flag: bool,
+}
+
+/// `DynScaledValue` with the percentage scale of `SmallValue`.
+pub type DynSmallValue = DynScaledValue<100>;
+
+// Call the generic function `Self::$func::<T>` for the type of `$kind`.
+macro_rules! with_kind {
+    ($kind:expr, $func:ident($($arg:expr),*)) => {
+        match $kind {
+            IntKind::U8 => Self::$func::<u8>($($arg),*),
+            IntKind::U16 => Self::$func::<u16>($($arg),*),
+            IntKind::U32 => Self::$func::<u32>($($arg),*),
+            IntKind::U64 => Self::$func::<u64>($($arg),*),
+            IntKind::U128 => Self::$func::<u128>($($arg),*),
+            IntKind::I8 => Self::$func::<i8>($($arg),*),
+            IntKind::I16 => Self::$func::<i16>($($arg),*),
+            IntKind::I32 => Self::$func::<i32>($($arg),*),
+            IntKind::I64 => Self::$func::<i64>($($arg),*),
+            IntKind::I128 => Self::$func::<i128>($($arg),*),
+        }
+    };
+}
+
+impl<const SCALE: u8> DynScaledValue<SCALE> {
+    /// Create an instance from the type and the raw parts,
+    /// the parts are checked like with `try_from` for `ScaledValue` of this type.
+    pub fn new(
+        kind: IntKind,
+        min_bits: u8,
+        percent: u8,
+        flag: bool,
+    ) -> Result<Self, SmallValueError> {
+        with_kind!(kind, checked(min_bits, percent, flag))
+    }
+
+    /// Returns the type that the value was encoded from.
+    pub fn kind(&self) -> IntKind {
+        self.kind
+    }
+
+    /// Returns the number of bits required to store the value.
+    pub fn min_bits(&self) -> u8 {
+        self.min_bits
+    }
+
+    /// Returns the percentage of the maximum value for the number of bits.
+    pub fn percent(&self) -> u8 {
+        self.percent
+    }
+
+    /// Returns the flag that indicates whether the number is negative.
+    pub fn flag(&self) -> bool {
+        self.flag
+    }
+
+    /// Returns the approximate value as `u128`, `None` if it is negative.
+    pub fn to_u128(&self) -> Option<u128> {
+        let (magnitude, negative) = self.decode();
+        (!negative).then_some(magnitude)
+    }
+
+    /// Returns the approximate value as `i128`, `None` if it does not fit (large `u128` values).
+    pub fn to_i128(&self) -> Option<i128> {
+        let (magnitude, negative) = self.decode();
+        if negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// Returns the approximate value as `f64` (large values are rounded).
+    pub fn to_f64(&self) -> f64 {
+        let (magnitude, negative) = self.decode();
+        let value = magnitude as f64;
+        if negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// Packs the value into `u16` like `ScaledValue::to_bits` (the type is not included).
+    pub fn to_bits(&self) -> u16 {
+        with_kind!(self.kind, packed(self.min_bits, self.percent, self.flag))
+    }
+
+    /// Unpacks the value of the given type, the reverse of `to_bits`.
+    pub fn from_bits(kind: IntKind, bits: u16) -> Result<Self, SmallValueError> {
+        with_kind!(kind, unpacked(bits))
+    }
+
+    // The absolute value of `approximate` and whether it is negative.
+    fn decode(&self) -> (u128, bool) {
+        with_kind!(self.kind, decoded(self.min_bits, self.percent, self.flag))
+    }
+
+    // The helpers for `with_kind`, `T` is the type of the kind.
+
+    fn checked<T: SpecialBytes>(
+        min_bits: u8,
+        percent: u8,
+        flag: bool,
+    ) -> Result<Self, SmallValueError>
+    where
+        Self: From<ScaledValue<T, SCALE>>,
+    {
+        ScaledValue::<T, SCALE>::try_from((min_bits, percent, flag)).map(Self::from)
+    }
+
+    fn decoded<T: SpecialBytes>(min_bits: u8, percent: u8, flag: bool) -> (u128, bool) {
+        let value = ScaledValue::<T, SCALE>::from_raw_unchecked(min_bits, percent, flag);
+        let magnitude = ScaledValue::<T, SCALE>::unsigned_abs(value.approximate());
+        (magnitude, flag && magnitude != 0)
+    }
+
+    fn packed<T: SpecialBytes>(min_bits: u8, percent: u8, flag: bool) -> u16 {
+        ScaledValue::<T, SCALE>::from_raw_unchecked(min_bits, percent, flag).to_bits()
+    }
+
+    fn unpacked<T: SpecialBytes>(bits: u16) -> Result<Self, SmallValueError>
+    where
+        Self: From<ScaledValue<T, SCALE>>,
+    {
+        ScaledValue::<T, SCALE>::from_bits(bits).map(Self::from)
+    }
+}
+
+impl<const SCALE: u8> From<DynScaledValue<SCALE>> for (u8, u8, bool) {
+    fn from(value: DynScaledValue<SCALE>) -> Self {
+        (value.min_bits, value.percent, value.flag)
+    }
+}
+
+// Each type is tagged with the kind of the same width.
+macro_rules! impl_dyn {
+    ($($t:ty => $kind:ident),*) => {
+        $(
+            impl<const SCALE: u8> From<ScaledValue<$t, SCALE>> for DynScaledValue<SCALE> {
+                fn from(value: ScaledValue<$t, SCALE>) -> Self {
+                    Self {
+                        kind: IntKind::$kind,
+                        min_bits: value.min_bits,
+                        percent: value.percent,
+                        flag: value.flag,
+                    }
+                }
+            }
+
+            /// Works for any kind, like the conversions between `ScaledValue` types
+            /// it fails if the number of bits or the sign is not available in `T`.
+            impl<const SCALE: u8> TryFrom<DynScaledValue<SCALE>> for ScaledValue<$t, SCALE> {
+                type Error = SmallValueError;
+
+                fn try_from(value: DynScaledValue<SCALE>) -> Result<Self, Self::Error> {
+                    Self::try_from((value.min_bits, value.percent, value.flag))
+                }
+            }
+        )*
+    };
+}
+
+impl_dyn! {
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128
+}
+
+#[cfg(target_pointer_width = "64")]
+impl_dyn!(usize => U64, isize => I64);
+#[cfg(target_pointer_width = "32")]
+impl_dyn!(usize => U32, isize => I32);
+#[cfg(target_pointer_width = "16")]
+impl_dyn!(usize => U16, isize => I16);
+
+#[cfg(not(tarpaulin_include))]
+impl<const SCALE: u8> fmt::Display for DynScaledValue<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (magnitude, negative) = self.decode();
+        write!(
+            f,
+            "{}{} ({})",
+            if negative { "-" } else { "" },
+            magnitude,
+            self.kind
+        )
+    }
+}
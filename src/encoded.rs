@@ -0,0 +1,61 @@
+use crate::{ScaledValue, SpecialBytes};
+
+// The operations work on the absolute values that the raw parts describe (always in `u128`),
+// so `T` is never used for the intermediate results and only the sign is calculated separately.
+// It is the same arithmetic as `checked_*` at about the same cost, only the conversions to `T` are skipped.
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Multiplication on the encoded values.
+    ///
+    /// The absolute values of both sides are calculated from `(min_bits, percent)` and multiplied in `u128`,
+    /// then the product is encoded once, so the result is the same as `checked_mul`.
+    /// Returns `None` only if the product does not fit in `T`.
+    /// ### Example
+    /// ```rust
+    /// let big = SmallValue::new(u64::MAX / 2);
+    /// assert!(big.mul_encoded(big).is_none());
+    /// let product = SmallValue::new(1u64 << 40).mul_encoded(SmallValue::new(1 << 20)).unwrap();
+    /// assert_eq!(product.min_bits(), 60);
+    /// ```
+    pub fn mul_encoded(&self, rhs: Self) -> Option<Self> {
+        let product = self
+            .encoded_magnitude()
+            .checked_mul(rhs.encoded_magnitude())?;
+        Self::from_encoded(product, self.flag != rhs.flag)
+    }
+
+    /// Division on the encoded values, the quotient is truncated like for integers.
+    ///
+    /// The result is the same as `checked_div`. Returns `None` only if the divisor is zero.
+    pub fn div_encoded(&self, rhs: Self) -> Option<Self> {
+        let quotient = self
+            .encoded_magnitude()
+            .checked_div(rhs.encoded_magnitude())?;
+        Self::from_encoded(quotient, self.flag != rhs.flag)
+    }
+
+    /// Raises the encoded value to the power of `exp`.
+    ///
+    /// The power is calculated in `u128` from the approximate value (not from the original number),
+    /// and is encoded once. Returns `None` if the result does not fit in `T`.
+    /// ### Example
+    /// ```rust
+    /// let cube = SmallValue::new(1000u64).pow_encoded(3).unwrap();
+    /// assert_eq!(cube, SmallValue::new(990u64.pow(3)));
+    /// ```
+    pub fn pow_encoded(&self, exp: u32) -> Option<Self> {
+        let power = self.encoded_magnitude().checked_pow(exp)?;
+        Self::from_encoded(power, self.flag && exp % 2 == 1)
+    }
+
+    // The absolute value of `approximate`.
+    fn encoded_magnitude(&self) -> u128 {
+        Self::magnitude(self.min_bits, self.percent).unwrap_or_default()
+    }
+
+    // Encode an absolute value if the number with the sign fits in `T` (`T::MIN` has no positive counterpart).
+    fn from_encoded(magnitude: u128, flag: bool) -> Option<Self> {
+        let flag = flag && magnitude != 0;
+        let last = Self::magnitude_range(*Self::min_bits_range().end(), flag);
+        (magnitude <= *last.end()).then(|| Self::new(Self::from_magnitude(magnitude, flag)))
+    }
+}
@@ -0,0 +1,42 @@
+use core::fmt;
+
+/// Errors that can occur when a `SmallValue` is built from raw parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmallValueError {
+    /// The number of bits is outside the range that `T` can produce
+    MinBitsOutOfRange { min_bits: u8, min: u8, max: u8 },
+    /// The percentage is outside the range that `SmallValue::new` can produce
+    PercentOutOfRange { percent: u8, min: u8, max: u8 },
+    /// The sign flag is set, but `T` is an unsigned type
+    NegativeUnsigned,
+    /// The number of bytes is not a multiple of the size of a packed value
+    InvalidByteLength { len: usize },
+    /// The number is not the approximate value of any valid encoding
+    NotRepresentable,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl fmt::Display for SmallValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MinBitsOutOfRange { min_bits, min, max } => write!(
+                f,
+                "number of bits {} is out of range {}..={}",
+                min_bits, min, max
+            ),
+            Self::PercentOutOfRange { percent, min, max } => write!(
+                f,
+                "percentage {} is out of range {}..={}",
+                percent, min, max
+            ),
+            Self::NegativeUnsigned => write!(f, "sign flag is set for an unsigned type"),
+            Self::InvalidByteLength { len } => {
+                write!(f, "byte length {} is not a multiple of 2", len)
+            }
+            Self::NotRepresentable => write!(f, "number is not a representable value"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SmallValueError {}
@@ -0,0 +1,175 @@
+use crate::{ScaledValue, SpecialBytes};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// A histogram with a bucket for every value of `ScaledValue`: a number is counted in the bucket of `new(number)`.
+///
+/// The buckets are about logarithmic (the width grows with the value), so the histogram covers the whole range
+/// of `T` with the same relative error everywhere. Only non-empty buckets are stored, by the packed value (`to_bits`),
+/// so `record` does not compare approximate values.
+/// `quantile` and `mean` are calculated from the approximate values of the buckets,
+/// so their error is the error of the encoding (see `max_relative_error`), `min` and `max` are exact.
+/// ### Example
+/// ```rust
+/// let mut latencies = ApproxHistogram::<u64>::new();
+/// for micros in [120, 95, 3000, 150, 110] {
+///     latencies.record(micros);
+/// }
+/// let p99 = latencies.quantile(0.99);
+/// let slowest = latencies.max(); // Some(3000)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ApproxHistogram<T: SpecialBytes, const SCALE: u8 = 100> {
+    buckets: BTreeMap<u16, u64>,
+    count: u64,
+    min: Option<T>,
+    max: Option<T>,
+}
+
+impl<T: SpecialBytes, const SCALE: u8> ApproxHistogram<T, SCALE> {
+    /// Creates an empty histogram.
+    pub fn new() -> Self {
+        Self {
+            buckets: BTreeMap::new(),
+            count: 0,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Counts the number in its bucket.
+    pub fn record(&mut self, number: T) {
+        self.record_n(number, 1);
+    }
+
+    /// Counts the number `n` times.
+    pub fn record_n(&mut self, number: T, n: u64) {
+        if n == 0 {
+            return;
+        }
+
+        // `new` returns canonical values, so equal values have equal bits
+        let value = ScaledValue::<T, SCALE>::new(number);
+        *self.buckets.entry(value.to_bits()).or_insert(0) += n;
+        self.count += n;
+        self.min = Some(self.min.map_or(number, |min| min.min(number)));
+        self.max = Some(self.max.map_or(number, |max| max.max(number)));
+    }
+
+    /// Adds the counts of the other histogram.
+    pub fn merge(&mut self, other: &Self) {
+        for (bits, n) in &other.buckets {
+            *self.buckets.entry(*bits).or_insert(0) += n;
+        }
+        self.count += other.count;
+        self.min = Self::combine(self.min, other.min, T::min);
+        self.max = Self::combine(self.max, other.max, T::max);
+    }
+
+    /// Returns the number of recorded numbers.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Checks whether nothing was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the smallest recorded number, `None` if the histogram is empty.
+    pub fn min(&self) -> Option<T> {
+        self.min
+    }
+
+    /// Returns the largest recorded number, `None` if the histogram is empty.
+    pub fn max(&self) -> Option<T> {
+        self.max
+    }
+
+    /// Returns the approximate mean of the recorded numbers, `None` if the histogram is empty.
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let sum: f64 = self
+            .unordered()
+            .map(|(value, n)| Self::to_f64(value.approximate()) * n as f64)
+            .sum();
+        Some(sum / self.count as f64)
+    }
+
+    /// Returns the approximate value of the bucket that contains the number at the position `q * count()`
+    /// of the recorded numbers in ascending order, `None` if the histogram is empty.
+    ///
+    /// `q` is clamped to `0.0..=1.0`, so `quantile(0.5)` is the median and `quantile(1.0)` is in the bucket of `max`.
+    pub fn quantile(&self, q: f64) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let q = q.clamp(0.0, 1.0);
+        let rank = ((q * self.count as f64) as u64).min(self.count - 1);
+        let mut below = 0;
+        for (value, n) in self.iter() {
+            below += n;
+            if below > rank {
+                return Some(value.approximate());
+            }
+        }
+        None
+    }
+
+    /// Returns an iterator over the non-empty buckets in ascending order, as the value and its count.
+    /// `ScaledValue::bucket` gives the range of the numbers in the bucket.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (ScaledValue<T, SCALE>, u64)> + '_ {
+        let mut buckets: Vec<_> = self.unordered().collect();
+        buckets.sort_unstable_by_key(|(value, _)| *value);
+        buckets.into_iter()
+    }
+
+    /// Removes all counts.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    // The buckets in the order of their bits, every stored key comes from `to_bits`.
+    fn unordered(&self) -> impl Iterator<Item = (ScaledValue<T, SCALE>, u64)> + '_ {
+        self.buckets
+            .iter()
+            .filter_map(|(bits, n)| Some((ScaledValue::from_bits(*bits).ok()?, *n)))
+    }
+
+    fn combine(a: Option<T>, b: Option<T>, pick: fn(T, T) -> T) -> Option<T> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(pick(a, b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn to_f64(number: T) -> f64 {
+        number.to_f64().unwrap_or_default()
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> Default for ApproxHistogram<T, SCALE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> Extend<T> for ApproxHistogram<T, SCALE> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for number in iter {
+            self.record(number);
+        }
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> FromIterator<T> for ApproxHistogram<T, SCALE> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut histogram = Self::new();
+        histogram.extend(iter);
+        histogram
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{ScaledValue, SpecialBytes};
+use core::fmt;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The range `min..=max` that is guaranteed to contain the original number.
+///
+/// Unlike the arithmetic of `SmallValue`, the operations do not re-encode the result,
+/// they return the range of all possible results for the numbers in both ranges.
+/// So the error of every input is propagated and the width of the result shows how uncertain it is.
+/// The operations panic on overflow like the operations of `T` (use the `checked_*` methods otherwise).
+/// ### Example
+/// ```rust
+/// let a = ApproxInterval::from(SmallValue::new(1000u64));
+/// let b = ApproxInterval::from(SmallValue::new(5000u64));
+/// let sum = a + b;
+/// assert!(sum.contains(6000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ApproxInterval<T: SpecialBytes> {
+    min: T,
+    max: T,
+}
+
+impl<T: SpecialBytes> ApproxInterval<T> {
+    /// Create an interval from its ends, they are swapped if `min > max`.
+    pub fn new(min: T, max: T) -> Self {
+        if min > max {
+            Self { min: max, max: min }
+        } else {
+            Self { min, max }
+        }
+    }
+
+    /// Returns the smallest possible number.
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    /// Returns the largest possible number.
+    pub fn max(&self) -> T {
+        self.max
+    }
+
+    /// Returns `max - min`, it always fits in `u128`.
+    pub fn width(&self) -> u128 {
+        match (self.min.to_i128(), self.max.to_i128()) {
+            (Some(min), Some(max)) => max.abs_diff(min),
+            // only `u128` does not fit in `i128`
+            _ => self.max.to_u128().unwrap_or_default() - self.min.to_u128().unwrap_or_default(),
+        }
+    }
+
+    /// Checks whether the number is in the interval.
+    pub fn contains(&self, number: T) -> bool {
+        self.min <= number && number <= self.max
+    }
+
+    /// Checked addition. Returns `None` if overflow occurred.
+    pub fn checked_add(&self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.min.checked_add(&rhs.min)?,
+            self.max.checked_add(&rhs.max)?,
+        ))
+    }
+
+    /// Checked subtraction. Returns `None` if overflow occurred.
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.min.checked_sub(&rhs.max)?,
+            self.max.checked_sub(&rhs.min)?,
+        ))
+    }
+
+    /// Checked multiplication. Returns `None` if overflow occurred.
+    pub fn checked_mul(&self, rhs: Self) -> Option<Self> {
+        Self::corners(self, &rhs, |a, b| a.checked_mul(&b))
+    }
+
+    /// Checked division. Returns `None` if the divisor can be zero or if overflow occurred.
+    pub fn checked_div(&self, rhs: Self) -> Option<Self> {
+        if rhs.contains(T::zero()) {
+            return None;
+        }
+        Self::corners(self, &rhs, |a, b| a.checked_div(&b))
+    }
+
+    // Multiplication and division (with a divisor of one sign) are monotone in each argument,
+    // so the result is between the smallest and the largest result for the ends.
+    fn corners(&self, rhs: &Self, op: impl Fn(T, T) -> Option<T>) -> Option<Self> {
+        let results = [
+            op(self.min, rhs.min)?,
+            op(self.min, rhs.max)?,
+            op(self.max, rhs.min)?,
+            op(self.max, rhs.max)?,
+        ];
+        let min = results.iter().copied().fold(results[0], T::min);
+        let max = results.iter().copied().fold(results[0], T::max);
+        Some(Self { min, max })
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: SpecialBytes + fmt::Display> fmt::Display for ApproxInterval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.min, self.max)
+    }
+}
+
+/// The exact number is an interval of a single point.
+impl<T: SpecialBytes> From<T> for ApproxInterval<T> {
+    fn from(number: T) -> Self {
+        Self {
+            min: number,
+            max: number,
+        }
+    }
+}
+
+/// The interval is the `bucket` of the value (all numbers that `new` maps to it).
+impl<T: SpecialBytes, const SCALE: u8> From<ScaledValue<T, SCALE>> for ApproxInterval<T> {
+    fn from(value: ScaledValue<T, SCALE>) -> Self {
+        value.interval()
+    }
+}
+
+macro_rules! impl_interval_ops {
+    ($($op:ident::$method:ident => $checked:ident, $message:literal);*) => {
+        $(
+            impl<T: SpecialBytes> $op for ApproxInterval<T> {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    self.$checked(rhs).expect($message)
+                }
+            }
+        )*
+    };
+}
+
+impl_interval_ops! {
+    Add::add => checked_add, "attempt to add with overflow";
+    Sub::sub => checked_sub, "attempt to subtract with overflow";
+    Mul::mul => checked_mul, "attempt to multiply with overflow";
+    Div::div => checked_div, "attempt to divide by an interval that contains zero or with overflow"
+}
+
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Returns the range that contains the original number.
+    ///
+    /// It is the `bucket` of the value, values that `new` never produces
+    /// (e.g. created with `try_from`) use `bounds` instead.
+    pub fn interval(&self) -> ApproxInterval<T> {
+        let bucket = self.bucket();
+        if bucket.is_empty() {
+            let (min, max) = self.bounds();
+            ApproxInterval::new(min, max)
+        } else {
+            ApproxInterval::new(*bucket.start(), *bucket.end())
+        }
+    }
+
+    /// Addition that also returns the range of the exact sum of the original numbers.
+    /// ### Example
+    /// ```rust
+    /// let (sum, interval) = SmallValue::new(1000u32).add_with_bounds(SmallValue::new(5000));
+    /// assert!(interval.contains(6000));
+    /// ```
+    pub fn add_with_bounds(&self, rhs: Self) -> (Self, ApproxInterval<T>) {
+        (*self + rhs, self.interval() + rhs.interval())
+    }
+
+    /// Subtraction that also returns the range of the exact difference of the original numbers.
+    pub fn sub_with_bounds(&self, rhs: Self) -> (Self, ApproxInterval<T>) {
+        (*self - rhs, self.interval() - rhs.interval())
+    }
+
+    /// Multiplication that also returns the range of the exact product of the original numbers.
+    pub fn mul_with_bounds(&self, rhs: Self) -> (Self, ApproxInterval<T>) {
+        (*self * rhs, self.interval() * rhs.interval())
+    }
+
+    /// Division that also returns the range of the exact quotient of the original numbers.
+    ///
+    /// Panics if the original divisor can be zero.
+    pub fn div_with_bounds(&self, rhs: Self) -> (Self, ApproxInterval<T>) {
+        let interval = self.interval() / rhs.interval();
+        (*self / rhs, interval)
+    }
+}
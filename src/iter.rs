@@ -0,0 +1,63 @@
+use crate::{ScaledValue, SpecialBytes};
+use core::borrow::Borrow;
+use core::iter::{Product, Sum};
+use num_traits::Saturating;
+
+// The approximations are accumulated in `T` without re-encoding, only the result is encoded.
+// Like for the primitive integers, overflow panics in debug builds.
+impl<T: SpecialBytes, const SCALE: u8> Sum for ScaledValue<T, SCALE> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::new(iter.fold(T::zero(), |sum, value| sum + value.approximate()))
+    }
+}
+
+impl<'a, T: SpecialBytes, const SCALE: u8> Sum<&'a Self> for ScaledValue<T, SCALE> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self::new(iter.fold(T::zero(), |sum, value| sum + value.approximate()))
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> Product for ScaledValue<T, SCALE> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::new(iter.fold(T::one(), |product, value| product * value.approximate()))
+    }
+}
+
+impl<'a, T: SpecialBytes, const SCALE: u8> Product<&'a Self> for ScaledValue<T, SCALE> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self::new(iter.fold(T::one(), |product, value| product * value.approximate()))
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Sum of the approximations that is encoded once. Returns `None` if overflow occurred.
+    /// ### Example
+    /// ```rust
+    /// let values = vec![SmallValue::new(1000u64); 10_000];
+    /// let sum = SmallValue::checked_sum(&values).unwrap();
+    /// assert_eq!(sum, SmallValue::new(values[0].approximate() * 10_000));
+    /// ```
+    pub fn checked_sum<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        iter.into_iter()
+            .try_fold(T::zero(), |sum, value| {
+                sum.checked_add(&value.borrow().approximate())
+            })
+            .map(Self::new)
+    }
+
+    /// Sum of the approximations that is encoded once.
+    /// The running sum is pinned at the bounds of `T` like with `saturating_add`.
+    pub fn saturating_sum<I>(iter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        Self::saturated(iter.into_iter().fold(T::zero(), |sum, value| {
+            Saturating::saturating_add(sum, value.borrow().approximate())
+        }))
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{Rounding, ScaledValue, SmallValueError, SpecialBytes};
+
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Convert a raw tuple created by versions before `2.0` to the current encoding.
+    ///
+    /// Positive tuples are encoded the same way and are only checked like with `try_from`.
+    /// Negative tuples are decoded with the old formula (the bits of `|x| - 1` and the percentage
+    /// with the error correction), the result is encoded again with `Rounding::Nearest`,
+    /// so the approximate value can change slightly. The tuples that the old formula decoded as zero
+    /// by mistake are restored as `-1` (zero bits) and `-T::MAX` (overflow near `T::MIN`).
+    /// ### Example
+    /// ```rust
+    /// // `SmallValue::new(-1000i32)` in `1.x`, it was decoded as `-1010`
+    /// let small_value = SmallValue::<i32>::from_legacy((10, 100, true))?;
+    /// assert_eq!(small_value.approximate(), -1000);
+    /// ```
+    pub fn from_legacy(raw: (u8, u8, bool)) -> Result<Self, SmallValueError> {
+        let (min_bits, percent, flag) = raw;
+        if !flag {
+            return Self::try_from(raw);
+        }
+        if !Self::is_signed() {
+            return Err(SmallValueError::NegativeUnsigned);
+        }
+
+        // `-1` needed zero bits
+        let max_bits = T::bits() - 1;
+        if min_bits > max_bits {
+            return Err(SmallValueError::MinBitsOutOfRange {
+                min_bits,
+                min: 0,
+                max: max_bits,
+            });
+        }
+
+        // the error correction could double the percentage
+        let max_percent = SCALE.checked_mul(2).map_or(u8::MAX, |max| max - 1);
+        if !(1..=max_percent).contains(&percent) {
+            return Err(SmallValueError::PercentOutOfRange {
+                percent,
+                min: 1,
+                max: max_percent,
+            });
+        }
+
+        // the old formula gave zero for `-1` and for the values that overflow `T`
+        let magnitude = Self::magnitude(min_bits, percent.saturating_add(1))
+            .and_then(T::from)
+            .unwrap_or_else(T::max_value)
+            .max(T::one());
+        Ok(Self::new_with(T::zero() - magnitude, Rounding::Nearest))
+    }
+}
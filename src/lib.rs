@@ -7,30 +7,105 @@
 //!
 //! - `u8` for the number of bits required to store the value.
 //! - `u8` for the percentage that describes the degree of approximation.
+//!   By default it is a percentage (`SmallValue`), `ScaledValue` allows any other number of steps up to `255`.
 //! - `bool` for storing the sign of the number (true if negative).
 //!
 //! **In total, this representation uses 24 bits**, but you can omit the `bool` if you are sure the number is positive,
-//! then **only 16 bits will be needed**. The approximate number will generally be smaller than the original,
-//! although there are exceptions, especially when working with negative values.
-//! For positive numbers, the approximation usually results in a slightly smaller value,
-//! but with negative numbers, the approximation could be either smaller or larger than the original.
-
-use num_traits::{CheckedRem, CheckedShl, PrimInt};
-use std::marker::PhantomData;
-use std::ops::{Add, Div, Mul, Rem, Sub};
-
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+//! then **only 16 bits will be needed**. The absolute value of the approximate number will generally be smaller
+//! than the original. Negative numbers use a sign-magnitude encoding: they are stored like their absolute values
+//! with the sign flag set, so the approximation of `-x` is always the negation of the approximation of `x`
+//! (`T::MIN` is stored like `-T::MAX`). Tuples created by versions before `2.0` can be converted with `from_legacy`.
+//!
+//! Not every valid encoding is produced by `new`: the shorter bit lengths overlap the lower part of the longer ones,
+//! so some values are only created by `new_with` or `try_from`. `iter_all`, `next_up`, `next_down` and
+//! `count_representable` enumerate all canonical values, `iter_reachable`, `next_up_reachable`,
+//! `next_down_reachable` and `count_reachable` only the values of `new` (see `is_reachable` and `bucket`).
+//!
+//! For storage the value can be packed into a single `u16` with `to_bits` (or `to_le_bytes`/`to_be_bytes`),
+//! the sign is stored in the same 16 bits, the layout is described in `SmallValue::to_bits`.
+//!
+//! The crate is `no_std` compatible: disable the default `std` feature to use it without the standard library.
+//! The `alloc` feature (enabled by `std`) adds `SmallValueVec`, a packed array of values, and `ApproxHistogram`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod accuracy;
+mod bucket;
+mod canonical;
+mod convert;
+mod counter;
+mod dynamic;
+mod encoded;
+mod error;
+#[cfg(feature = "alloc")]
+mod histogram;
+mod interval;
+mod iter;
+mod legacy;
+mod math;
+mod non_zero;
+mod ops;
+mod packed;
+mod representable;
+mod rounding;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod shift;
+mod sort_key;
+#[cfg(feature = "alloc")]
+mod vec;
+
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, RangeInclusive, Rem, Sub};
+pub use counter::ApproxCounter;
+pub use dynamic::{DynScaledValue, DynSmallValue, IntKind};
+pub use error::SmallValueError;
+#[cfg(feature = "alloc")]
+pub use histogram::ApproxHistogram;
+pub use interval::ApproxInterval;
+use num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
+use num_traits::{CheckedRem, CheckedShl, PrimInt, Saturating, SaturatingMul};
+pub use rounding::Rounding;
+#[cfg(feature = "alloc")]
+pub use vec::{
+    ScaledValueSlice, ScaledValueVec, ScaledValueVecIter, SmallValueSlice, SmallValueVec,
+};
+
+#[derive(Debug, Clone, Copy)]
 /// The structure stores the resulting number
-/// in a compressed format from which an approximated number can be obtained
-pub struct SmallValue<T: SpecialBytes> {
+/// in a compressed format from which an approximated number can be obtained.
+///
+/// `SCALE` is the number of steps the maximum value of the bit length is divided into,
+/// the stored percentage is the number of such steps. More steps give a smaller approximation error
+/// (about `1 / SCALE` for large numbers), the percentage still fits in a single `u8`.
+/// ### Example
+/// ```rust
+/// let small_value = ScaledValue::<u64, 255>::new(8838183818381831838);
+/// ```
+pub struct ScaledValue<T: SpecialBytes, const SCALE: u8> {
     min_bits: u8,
     percent: u8,
     flag: bool,
     _phantom: PhantomData<T>,
 }
 
+/// The default representation, the maximum value of the bit length is divided into `100` steps (percentages)
+pub type SmallValue<T> = ScaledValue<T, 100>;
+
 /// The trait is used to determine the number of bits required to store a number
-pub trait SpecialBytes: PrimInt + Default + CheckedShl + CheckedRem {
+pub trait SpecialBytes:
+    PrimInt
+    + Default
+    + CheckedShl
+    + CheckedRem
+    + SaturatingMul
+    + OverflowingAdd
+    + OverflowingSub
+    + OverflowingMul
+{
     /// Returns the number of bits required to store a number
     fn bits() -> u8;
 }
@@ -48,24 +123,108 @@ macro_rules! impl_default_bits {
 }
 
 impl_default_bits! {
+    u8 => 8,
+    u16 => 16,
     u32 => 32,
     u64 => 64,
     u128 => 128,
+    usize => usize::BITS as u8,
+    i8 => 8,
+    i16 => 16,
     i32 => 32,
     i64 => 64,
-    i128 => 128
+    i128 => 128,
+    isize => isize::BITS as u8
 }
 
-impl<T: SpecialBytes> SmallValue<T> {
-    // Calculate the number of bits required to represent a number.
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    // At least two steps are needed: the percentage is in the range `1..SCALE`.
+    const SCALE_CHECK: () = assert!(SCALE >= 2, "`SCALE` must be at least 2");
+
+    // Calculate the number of bits required to represent the absolute value of a number.
+    // `T::MIN` has no positive counterpart, it takes as many bits as `T::MAX`.
     fn bit_size(number: T) -> u8 {
-        (number == T::zero()).then_some(1).unwrap_or_else(|| {
-            T::bits().saturating_sub(if number < T::zero() {
-                (!number).leading_zeros()
-            } else {
-                number.leading_zeros()
-            } as u8)
-        })
+        Self::bits_of(Self::unsigned_abs(number).min(Self::unsigned_abs(T::max_value())))
+    }
+
+    // The same for an absolute value.
+    fn bits_of(magnitude: u128) -> u8 {
+        (128 - magnitude.leading_zeros()).max(1) as u8
+    }
+
+    // Check whether `T` can store negative numbers.
+    fn is_signed() -> bool {
+        T::min_value() < T::zero()
+    }
+
+    // The absolute values of the numbers for which `bit_size` returns `min_bits`.
+    // The largest negative range also contains `T::MIN`.
+    fn magnitude_range(min_bits: u8, flag: bool) -> RangeInclusive<u128> {
+        let low = match min_bits {
+            0 | 1 => u128::from(flag),
+            bits => 1u128 << (bits - 1),
+        };
+        let high = u128::MAX
+            .checked_shr(128 - u32::from(min_bits))
+            .unwrap_or_default();
+
+        if flag && min_bits == T::bits() - 1 {
+            low..=high + 1
+        } else {
+            low..=high
+        }
+    }
+
+    // Convert an absolute value back to the number with the given sign.
+    fn from_magnitude(magnitude: u128, flag: bool) -> T {
+        if flag {
+            // `wrapping_neg` gives `i128::MIN` for `2^127`
+            T::from((magnitude as i128).wrapping_neg())
+        } else {
+            T::from(magnitude)
+        }
+        .unwrap_or_default()
+    }
+
+    // The range of bits that `new` can produce for `T`.
+    fn min_bits_range() -> RangeInclusive<u8> {
+        let max = if Self::is_signed() {
+            T::bits() - 1
+        } else {
+            T::bits()
+        };
+        1..=max
+    }
+
+    // The range of percentages that `new` can produce.
+    fn percent_range() -> RangeInclusive<u8> {
+        1..=SCALE - 1
+    }
+
+    fn validate(min_bits: u8, percent: u8, flag: bool) -> Result<(), SmallValueError> {
+        if flag && !Self::is_signed() {
+            return Err(SmallValueError::NegativeUnsigned);
+        }
+
+        let bits = Self::min_bits_range();
+        if !bits.contains(&min_bits) {
+            return Err(SmallValueError::MinBitsOutOfRange {
+                min_bits,
+                min: *bits.start(),
+                max: *bits.end(),
+            });
+        }
+
+        let percents = Self::percent_range();
+        if !percents.contains(&percent) {
+            return Err(SmallValueError::PercentOutOfRange {
+                percent,
+                min: *percents.start(),
+                max: *percents.end(),
+            });
+        }
+
+        Ok(())
     }
 
     // Calculate the maximum value that can be represented using a given number of bits.
@@ -82,41 +241,121 @@ impl<T: SpecialBytes> SmallValue<T> {
 
     // Calculate the approximate value based on a percentage.
     fn calculate_part_from_percentage(percentage: u8, total: T) -> T {
-        let hundred = T::from(100u8).unwrap_or_default();
-        let percentage = T::from(percentage).unwrap_or_default();
+        total
+            .to_u128()
+            .and_then(|total| Self::part_of(percentage, total))
+            .and_then(T::from)
+            .unwrap_or_else(T::zero)
+    }
+
+    // The same calculation in `u128`: `total` is never negative,
+    // so `SCALE` and the intermediate product always fit (even for `i8`).
+    fn part_of(percentage: u8, total: u128) -> Option<u128> {
+        let () = Self::SCALE_CHECK;
+        let percentage = u128::from(percentage);
+        let scale = u128::from(SCALE);
+
+        if total < scale {
+            Some(total * percentage / scale)
+        } else {
+            (total / scale).checked_mul(percentage)
+        }
+    }
 
-        if hundred > total {
-            if let Some(product) = total.checked_mul(&percentage) {
-                if let Some(result) = product.checked_div(&hundred) {
-                    return result;
+    // The absolute value of `approximate` for the raw parts,
+    // `None` if it does not fit in `T` (then `approximate` returns zero).
+    fn magnitude(min_bits: u8, percent: u8) -> Option<u128> {
+        Self::bit_pow(min_bits)
+            .to_u128()
+            .and_then(|total| Self::part_of(percent, total))
+            .filter(|magnitude| T::from(*magnitude).is_some())
+    }
+
+    // The smallest percentage whose absolute value is at least `value`, `None` if every percentage gives a smaller value.
+    fn first_at_least(min_bits: u8, value: u128) -> Option<u8> {
+        let range = Self::percent_range();
+        let total = Self::bit_pow(min_bits).to_u128().unwrap_or_default();
+        let scale = u128::from(SCALE);
+
+        // the inverse of `part_of`, every part fits in `T` because it is not greater than `total`
+        let percent = if value == 0 {
+            0
+        } else if total < scale {
+            if value > total {
+                return None;
+            }
+            (value * scale).div_ceil(total)
+        } else {
+            value.div_ceil(total / scale)
+        };
+        (percent <= u128::from(*range.end())).then(|| (percent as u8).max(*range.start()))
+    }
+
+    // The valid encoding with the smallest absolute value greater than `value` as `(min_bits, percent, magnitude)`.
+    // Shorter bit lengths have only smaller values, and the search stops at the bit length
+    // whose smallest value is not better than the one already found.
+    fn next_magnitude(value: u128) -> Option<(u8, u8, u128)> {
+        let target = value.checked_add(1)?;
+        let first = *Self::percent_range().start();
+        let mut best: Option<(u8, u8, u128)> = None;
+
+        for min_bits in Self::bits_of(value)..=*Self::min_bits_range().end() {
+            let smallest = Self::magnitude(min_bits, first);
+            if matches!((best, smallest), (Some((_, _, best)), Some(smallest)) if smallest >= best)
+            {
+                break;
+            }
+
+            let candidate = Self::first_at_least(min_bits, target)
+                .and_then(|percent| Some((min_bits, percent, Self::magnitude(min_bits, percent)?)));
+            if let Some(candidate) = candidate {
+                if best.is_none_or(|best| candidate.2 < best.2) {
+                    best = Some(candidate);
                 }
             }
         }
-
-        total
-            .checked_div(&hundred)
-            .and_then(|part| part.checked_mul(&percentage))
-            .unwrap_or_else(T::zero)
+        best
     }
 
-    fn calculate_error_rate(original: T, approximate: T) -> T {
-        let diff = original
-            .checked_sub(&approximate)
-            .and_then(|sub| sub.to_f64())
-            .and_then(|diff| original.to_f64().map(|original| diff / original))
-            .map(|div| div * 100.0)
-            .unwrap_or_default();
+    // The valid encoding with the largest absolute value not greater than `value` as `(min_bits, percent, magnitude)`,
+    // the search stops at the bit length whose smallest value is already greater.
+    fn prev_magnitude(value: u128) -> (u8, u8, u128) {
+        let (first, last) = (*Self::percent_range().start(), *Self::percent_range().end());
+        // `(1, 1)` is zero
+        let mut best = (1, first, 0);
+
+        let start = Self::bits_of(value).saturating_sub(1).max(1);
+        for min_bits in start..=*Self::min_bits_range().end() {
+            if Self::magnitude(min_bits, first).is_none_or(|smallest| smallest > value) {
+                break;
+            }
 
-        if let Some(percent) = T::from(diff) {
-            percent
-        } else {
-            T::zero()
+            let percent = match Self::first_at_least(min_bits, value.saturating_add(1)) {
+                Some(percent) if percent > first => percent - 1,
+                Some(_) => continue,
+                None => last,
+            };
+            match Self::magnitude(min_bits, percent) {
+                Some(magnitude) if magnitude > best.2 && magnitude <= value => {
+                    best = (min_bits, percent, magnitude)
+                }
+                _ => {}
+            }
         }
+        best
+    }
+
+    // The absolute value of a number, it always fits in `u128` (even `i128::MIN`).
+    fn unsigned_abs(number: T) -> u128 {
+        number
+            .to_u128()
+            .or_else(|| number.to_i128().map(i128::unsigned_abs))
+            .unwrap_or_default()
     }
 }
 
-impl<T: SpecialBytes> SmallValue<T> {
-    /// Create a new instance of SmallValue.
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Create a new instance of SmallValue (or `ScaledValue` with any other `SCALE`).
     ///
     /// ---
     /// You can use `Into` to convert a number to a `SmallValue`.
@@ -128,65 +367,51 @@ impl<T: SpecialBytes> SmallValue<T> {
     /// ---
     /// after creation, the number can be represented in a smaller
     /// representation `(min_bits: u8, percent:u8, flag: bool)` (use `into`).
+    /// The reverse operation checks the tuple, so use `try_into`.
     /// ### Example
     /// ```rust
     /// let tuple: (u8, u8, bool) = small_value.into();
     /// // and the reverse operation
     /// let tuple = (8, 50, false);
-    /// let small_value: SmallValue<u32> = tuple.into();
+    /// let small_value: SmallValue<u32> = tuple.try_into()?;
     /// ```
     pub fn new(number: T) -> Self {
-        let min_bits = Self::bit_size(number);
-        let mut percent = 99;
-
-        let (abs_number, flag) = if number < T::zero() {
-            if T::min_value() == number {
-                return Self {
-                    min_bits,
-                    percent,
-                    flag: true,
-                    _phantom: PhantomData,
-                };
-            }
-            (T::zero() - number, true)
+        Self::encode(number).normalize()
+    }
+
+    // The encoding before normalization: the bits of the absolute value
+    // and the largest percentage that gives a smaller number.
+    fn encode(number: T) -> Self {
+        let flag = number < T::zero();
+        // `T::MIN` has no positive counterpart, it is encoded like `-T::MAX`
+        let abs_number = if flag {
+            T::zero().checked_sub(&number).unwrap_or_else(T::max_value)
         } else {
-            (number, false)
+            number
         };
+        let min_bits = Self::bit_size(abs_number);
 
+        let mut percent = SCALE - 1;
         while percent > 1 {
             let approx = Self::calculate_part_from_percentage(percent, Self::bit_pow(min_bits));
             if abs_number > approx {
-                return Self {
-                    min_bits,
-                    percent: if flag {
-                        percent
-                            + Self::calculate_error_rate(
-                                abs_number,
-                                SmallValue::from((min_bits, percent, false)).approximate(),
-                            )
-                            .to_u8()
-                            .unwrap_or_default()
-                    } else {
-                        percent
-                    },
-                    flag,
-                    _phantom: PhantomData,
-                };
+                break;
             }
             percent -= 1;
         }
 
         Self {
             min_bits,
-            percent: 1,
+            percent,
             flag,
             _phantom: PhantomData,
         }
     }
 
     /// Returns the approximate value of the number.
-    /// The approximate number will usually be less than the original one,
-    /// although there are exceptions, especially when working with negative values or with small numbers (less than 100).
+    /// The absolute value of the approximate number will usually be less than the original one,
+    /// although there are exceptions for small numbers (less than `SCALE`).
+    /// Negative numbers are encoded like their absolute values, so `new(-x)` approximates to `-new(x)`.
     ///
     /// ### Example
     /// ```rust
@@ -199,14 +424,8 @@ impl<T: SpecialBytes> SmallValue<T> {
     /// // The approximate value: 8822848225945509419002221297664
     /// ```
     pub fn approximate(&self) -> T {
-        let abs_value = Self::calculate_part_from_percentage(
-            if self.flag {
-                self.percent.saturating_add(1)
-            } else {
-                self.percent
-            },
-            Self::bit_pow(self.min_bits),
-        );
+        let abs_value =
+            Self::calculate_part_from_percentage(self.percent, Self::bit_pow(self.min_bits));
 
         if self.flag {
             T::zero() - abs_value
@@ -217,30 +436,51 @@ impl<T: SpecialBytes> SmallValue<T> {
 
     /// Returns a tuple with the minimum and maximum values for this object.
     ///
-    /// The minimum value (`min`) is calculated using the `approximate` method.
-    /// The maximum value (`max`) is calculated by creating a new object with an increased percentage value and calling the `approximate` method.
+    /// One end is the `approximate` value, the other one is calculated by creating a new object
+    /// with an increased percentage value and calling the `approximate` method
+    /// (for negative numbers it is `min`, otherwise `max`).
     ///
     /// It is generally guaranteed that the original number is not less than `min`, but also not greater than `max`.
-    /// However, there may be exceptions for negative numbers. Additionally, if the number is close to the maximum value of the chosen type (`T::MAX`),
+    /// However, if the number is close to the maximum value of the chosen type (`T::MAX`),
     /// the `max` value might be lower than the original number.
     /// For example, if the type `T` is `u32`, and the number is close to `u32::MAX`, then the `max` boundary will likely be lower than the original number (the same thing can happen if `min` is close to `u32::MIN`).
     ///
+    /// Use `bucket` to get the exact range of the original numbers.
     pub fn bounds(&self) -> (T, T) {
-        let min = self.approximate();
-
-        (
-            min,
-            Self::from((
-                self.min_bits,
-                if self.flag {
-                    self.percent.saturating_sub(2)
-                } else {
-                    self.percent.saturating_add(1)
-                },
-                self.flag,
-            ))
-            .approximate(),
-        )
+        let approx = self.approximate();
+        let next =
+            Self::from_raw_unchecked(self.min_bits, self.percent.saturating_add(1), self.flag)
+                .approximate();
+
+        if self.flag {
+            (next, approx)
+        } else {
+            (approx, next)
+        }
+    }
+
+    /// Create an instance from raw parts without any checks.
+    ///
+    /// The parts are not required to be produced by `new`, so the approximate value
+    /// of such an instance can be meaningless. Use `try_from` for data
+    /// that comes from disk or from the network.
+    /// ### Example
+    /// ```rust
+    /// let small_value = SmallValue::<u32>::from_raw_unchecked(8, 50, false);
+    /// assert!(small_value.is_valid());
+    /// ```
+    pub fn from_raw_unchecked(min_bits: u8, percent: u8, flag: bool) -> Self {
+        Self {
+            min_bits,
+            percent,
+            flag,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Checks that the raw parts are in the range that `new` can produce for `T`.
+    pub fn is_valid(&self) -> bool {
+        Self::validate(self.min_bits, self.percent, self.flag).is_ok()
     }
 
     /// Returns the minimum number of bits required to represent the number.
@@ -263,8 +503,8 @@ impl<T: SpecialBytes> SmallValue<T> {
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T: SpecialBytes> std::fmt::Display for SmallValue<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: SpecialBytes, const SCALE: u8> core::fmt::Display for ScaledValue<T, SCALE> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "Exponent: {}, Percentage: {}",
@@ -274,106 +514,89 @@ impl<T: SpecialBytes> std::fmt::Display for SmallValue<T> {
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T: SpecialBytes> Default for SmallValue<T> {
+impl<T: SpecialBytes, const SCALE: u8> Default for ScaledValue<T, SCALE> {
     fn default() -> Self {
         Self::new(T::default())
     }
 }
 
-impl<T: SpecialBytes> PartialOrd for SmallValue<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
+impl<T: SpecialBytes, const SCALE: u8> TryFrom<(u8, u8, bool)> for ScaledValue<T, SCALE> {
+    type Error = SmallValueError;
 
-impl<T: SpecialBytes> Ord for SmallValue<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.approximate().cmp(&other.approximate())
+    fn try_from((min_bits, percent, flag): (u8, u8, bool)) -> Result<Self, Self::Error> {
+        Self::validate(min_bits, percent, flag)?;
+        Ok(Self::from_raw_unchecked(min_bits, percent, flag).normalize())
     }
 }
 
-impl<T: SpecialBytes> From<(u8, u8, bool)> for SmallValue<T> {
-    fn from((min_bits, percent, flag): (u8, u8, bool)) -> Self {
-        Self {
-            min_bits,
-            percent,
-            flag,
-            _phantom: PhantomData,
-        }
-    }
-}
+impl<T: SpecialBytes, const SCALE: u8> TryFrom<(u8, u8)> for ScaledValue<T, SCALE> {
+    type Error = SmallValueError;
 
-impl<T: SpecialBytes> From<(u8, u8)> for SmallValue<T> {
-    fn from((min_bits, percent): (u8, u8)) -> Self {
-        Self {
-            min_bits,
-            percent,
-            flag: false,
-            _phantom: PhantomData,
-        }
+    fn try_from((min_bits, percent): (u8, u8)) -> Result<Self, Self::Error> {
+        Self::try_from((min_bits, percent, false))
     }
 }
 
-impl<T: SpecialBytes> From<SmallValue<T>> for (u8, u8, bool) {
-    fn from(value: SmallValue<T>) -> Self {
+impl<T: SpecialBytes, const SCALE: u8> From<ScaledValue<T, SCALE>> for (u8, u8, bool) {
+    fn from(value: ScaledValue<T, SCALE>) -> Self {
         (value.min_bits, value.percent, value.flag)
     }
 }
 
-impl<T: SpecialBytes> From<SmallValue<T>> for (u8, u8) {
-    fn from(value: SmallValue<T>) -> Self {
+impl<T: SpecialBytes, const SCALE: u8> From<ScaledValue<T, SCALE>> for (u8, u8) {
+    fn from(value: ScaledValue<T, SCALE>) -> Self {
         (value.min_bits, value.percent)
     }
 }
 
-impl<T: SpecialBytes> From<T> for SmallValue<T> {
+impl<T: SpecialBytes, const SCALE: u8> From<T> for ScaledValue<T, SCALE> {
     fn from(number: T) -> Self {
         Self::new(number)
     }
 }
 
-impl<T: SpecialBytes> Add for SmallValue<T> {
-    type Output = SmallValue<T>;
+impl<T: SpecialBytes, const SCALE: u8> Add for ScaledValue<T, SCALE> {
+    type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        SmallValue::new(self.approximate() + rhs.approximate())
+        Self::new(self.approximate() + rhs.approximate())
     }
 }
 
-impl<T: SpecialBytes> Sub for SmallValue<T> {
-    type Output = SmallValue<T>;
+impl<T: SpecialBytes, const SCALE: u8> Sub for ScaledValue<T, SCALE> {
+    type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        SmallValue::new(self.approximate() - rhs.approximate())
+        Self::new(self.approximate() - rhs.approximate())
     }
 }
 
-impl<T: SpecialBytes> Mul for SmallValue<T> {
-    type Output = SmallValue<T>;
+impl<T: SpecialBytes, const SCALE: u8> Mul for ScaledValue<T, SCALE> {
+    type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        SmallValue::new(self.approximate() * rhs.approximate())
+        Self::new(self.approximate() * rhs.approximate())
     }
 }
 
-impl<T: SpecialBytes> Div for SmallValue<T> {
-    type Output = SmallValue<T>;
+impl<T: SpecialBytes, const SCALE: u8> Div for ScaledValue<T, SCALE> {
+    type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        SmallValue::new(self.approximate() / rhs.approximate())
+        Self::new(self.approximate() / rhs.approximate())
     }
 }
 
-impl<T: SpecialBytes> Rem for SmallValue<T> {
-    type Output = SmallValue<T>;
+impl<T: SpecialBytes, const SCALE: u8> Rem for ScaledValue<T, SCALE> {
+    type Output = Self;
 
     fn rem(self, rhs: Self) -> Self::Output {
-        SmallValue::new(self.approximate() % rhs.approximate())
+        Self::new(self.approximate() % rhs.approximate())
     }
 }
 
 // wrapping, checked:  add,sub, mul, div, rem
-impl<T: SpecialBytes> SmallValue<T> {
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
     /// Checked addition. Returns `None` if overflow occurred.
     pub fn checked_add(&self, rhs: Self) -> Option<Self> {
         self.approximate()
@@ -409,3 +632,141 @@ impl<T: SpecialBytes> SmallValue<T> {
             .map(Self::new)
     }
 }
+
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    // The approximation of `T::MAX` (`T::MIN`) can be inside the range of `T`,
+    // `new` would encode it one step lower, so such results are pinned to `new(T::MAX)` (`new(T::MIN)`).
+    fn saturated(value: T) -> Self {
+        let (min, max) = (Self::new(T::min_value()), Self::new(T::max_value()));
+        if value >= max.approximate() {
+            max
+        } else if value <= min.approximate() {
+            min
+        } else {
+            Self::new(value)
+        }
+    }
+
+    /// Saturating addition. Pins the result at the bounds of `T` instead of overflowing.
+    ///
+    /// Results at the approximation of `T::MAX` (`T::MIN`) or beyond it give `new(T::MAX)` (`new(T::MIN)`),
+    /// so a saturated counter stays at the same value.
+    /// ### Example
+    /// ```rust
+    /// let counter = SmallValue::new(u8::MAX).saturating_add(SmallValue::new(100));
+    /// assert_eq!(counter, SmallValue::new(u8::MAX));
+    /// ```
+    pub fn saturating_add(&self, rhs: Self) -> Self {
+        Self::saturated(Saturating::saturating_add(
+            self.approximate(),
+            rhs.approximate(),
+        ))
+    }
+
+    /// Saturating subtraction. Pins the result at the bounds of `T` instead of overflowing.
+    pub fn saturating_sub(&self, rhs: Self) -> Self {
+        Self::saturated(Saturating::saturating_sub(
+            self.approximate(),
+            rhs.approximate(),
+        ))
+    }
+
+    /// Saturating multiplication. Pins the result at the bounds of `T` instead of overflowing.
+    pub fn saturating_mul(&self, rhs: Self) -> Self {
+        Self::saturated(SaturatingMul::saturating_mul(
+            &self.approximate(),
+            &rhs.approximate(),
+        ))
+    }
+
+    /// Saturating division. Only `T::MIN / -1` can overflow, it gives `T::MAX`.
+    ///
+    /// Panics if the divisor is zero.
+    pub fn saturating_div(&self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.approximate(), rhs.approximate());
+        // the only overflow is `T::MIN / -1`
+        Self::saturated(lhs.checked_div(&rhs).unwrap_or_else(|| {
+            if rhs == T::zero() {
+                lhs / rhs
+            } else {
+                T::max_value()
+            }
+        }))
+    }
+
+    /// Wrapping addition. Wraps around at the bounds of `T`.
+    pub fn wrapping_add(&self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Wrapping subtraction. Wraps around at the bounds of `T`.
+    pub fn wrapping_sub(&self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Wrapping multiplication. Wraps around at the bounds of `T`.
+    pub fn wrapping_mul(&self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// Wrapping division. Only `T::MIN / -1` can overflow, it gives `T::MIN`.
+    ///
+    /// Panics if the divisor is zero.
+    pub fn wrapping_div(&self, rhs: Self) -> Self {
+        self.overflowing_div(rhs).0
+    }
+
+    /// Wrapping remainder. Only `T::MIN % -1` can overflow, it gives zero.
+    ///
+    /// Panics if the divisor is zero.
+    pub fn wrapping_rem(&self, rhs: Self) -> Self {
+        self.overflowing_rem(rhs).0
+    }
+
+    /// Addition that returns the wrapped result and whether an overflow occurred.
+    pub fn overflowing_add(&self, rhs: Self) -> (Self, bool) {
+        let (value, overflow) =
+            OverflowingAdd::overflowing_add(&self.approximate(), &rhs.approximate());
+        (Self::new(value), overflow)
+    }
+
+    /// Subtraction that returns the wrapped result and whether an overflow occurred.
+    pub fn overflowing_sub(&self, rhs: Self) -> (Self, bool) {
+        let (value, overflow) =
+            OverflowingSub::overflowing_sub(&self.approximate(), &rhs.approximate());
+        (Self::new(value), overflow)
+    }
+
+    /// Multiplication that returns the wrapped result and whether an overflow occurred.
+    pub fn overflowing_mul(&self, rhs: Self) -> (Self, bool) {
+        let (value, overflow) =
+            OverflowingMul::overflowing_mul(&self.approximate(), &rhs.approximate());
+        (Self::new(value), overflow)
+    }
+
+    /// Division that returns the wrapped result and whether an overflow occurred.
+    ///
+    /// Panics if the divisor is zero.
+    pub fn overflowing_div(&self, rhs: Self) -> (Self, bool) {
+        let (lhs, rhs) = (self.approximate(), rhs.approximate());
+        match lhs.checked_div(&rhs) {
+            Some(value) => (Self::new(value), false),
+            // the only overflow is `T::MIN / -1`
+            None if rhs != T::zero() => (Self::new(T::min_value()), true),
+            None => (Self::new(lhs / rhs), false),
+        }
+    }
+
+    /// Remainder that returns the wrapped result and whether an overflow occurred.
+    ///
+    /// Panics if the divisor is zero.
+    pub fn overflowing_rem(&self, rhs: Self) -> (Self, bool) {
+        let (lhs, rhs) = (self.approximate(), rhs.approximate());
+        match CheckedRem::checked_rem(&lhs, &rhs) {
+            Some(value) => (Self::new(value), false),
+            // the only overflow is `T::MIN % -1`
+            None if rhs != T::zero() => (Self::new(T::zero()), true),
+            None => (Self::new(lhs % rhs), false),
+        }
+    }
+}
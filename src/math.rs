@@ -0,0 +1,112 @@
+use crate::{ScaledValue, SpecialBytes};
+
+// The functions use the approximate value, its absolute value is calculated from the raw parts in `u128`.
+// The error bounds are given for a value created with `new(x)`.
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Returns the base 2 logarithm of the approximate value, rounded down.
+    ///
+    /// It is `ilog2(x)` or `ilog2(x) - 1`, because the approximation can fall below a power of two.
+    /// The exact `ilog2(x)` of the original number is `min_bits() - 1`.
+    ///
+    /// Panics if the approximate value is zero or negative.
+    pub fn ilog2(&self) -> u32 {
+        self.checked_ilog2()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the base 2 logarithm of the approximate value, rounded down.
+    /// Returns `None` if the approximate value is zero or negative.
+    pub fn checked_ilog2(&self) -> Option<u32> {
+        self.nonzero_magnitude()
+            .filter(|_| !self.flag)
+            .map(u128::ilog2)
+    }
+
+    /// Returns the base 10 logarithm of the approximate value, rounded down.
+    ///
+    /// It is `ilog10(x)` or `ilog10(x) - 1`, because the approximation can fall below a power of ten.
+    ///
+    /// Panics if the approximate value is zero or negative.
+    pub fn ilog10(&self) -> u32 {
+        self.checked_ilog10()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the base 10 logarithm of the approximate value, rounded down.
+    /// Returns `None` if the approximate value is zero or negative.
+    pub fn checked_ilog10(&self) -> Option<u32> {
+        self.nonzero_magnitude()
+            .filter(|_| !self.flag)
+            .map(u128::ilog10)
+    }
+
+    /// Returns the square root of the approximate value rounded down, the result is encoded again.
+    ///
+    /// The relative error of the root is about half of the error of the value,
+    /// plus the error of the encoding of the result.
+    ///
+    /// Panics if the approximate value is negative.
+    /// ### Example
+    /// ```rust
+    /// let variance = SmallValue::new(1_000_000u64);
+    /// let deviation = variance.isqrt().approximate(); // 990
+    /// ```
+    pub fn isqrt(&self) -> Self {
+        assert!(
+            !self.is_negative(),
+            "argument of integer square root cannot be negative"
+        );
+        let magnitude = Self::magnitude(self.min_bits, self.percent).unwrap_or_default();
+        Self::new(Self::from_magnitude(magnitude.isqrt(), false))
+    }
+
+    /// Raises the approximate value to the power of `exp`, the result is encoded once.
+    ///
+    /// The relative error of the power is about `exp` times the error of the value.
+    ///
+    /// Panics if the result does not fit in `T` (see `checked_pow`).
+    pub fn pow(&self, exp: u32) -> Self {
+        self.checked_pow(exp)
+            .expect("attempt to multiply with overflow")
+    }
+
+    /// Checked exponentiation. Returns `None` if the result does not fit in `T`.
+    /// The same as `pow_encoded`.
+    pub fn checked_pow(&self, exp: u32) -> Option<Self> {
+        self.pow_encoded(exp)
+    }
+
+    /// Returns the absolute value, only the sign flag is cleared, so it is exact
+    /// (`T::MIN` is encoded like `-T::MAX`, so there is no overflow).
+    pub fn abs(&self) -> Self {
+        Self::from_raw_unchecked(self.min_bits, self.percent, false)
+    }
+
+    /// Returns the sign of the approximate value: `-1`, `0` or `1`.
+    ///
+    /// Small numbers can approximate to zero, then `0` is returned even for `new(-1)`.
+    pub fn signum(&self) -> T {
+        if self.is_negative() {
+            T::zero() - T::one()
+        } else if self.is_positive() {
+            T::one()
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Checks whether the approximate value is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.flag && self.nonzero_magnitude().is_some()
+    }
+
+    /// Checks whether the approximate value is greater than zero.
+    pub fn is_positive(&self) -> bool {
+        !self.flag && self.nonzero_magnitude().is_some()
+    }
+
+    // The absolute value of `approximate`, if it is not zero.
+    fn nonzero_magnitude(&self) -> Option<u128> {
+        Self::magnitude(self.min_bits, self.percent).filter(|magnitude| *magnitude > 0)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::ScaledValue;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+// `NonZero*` types are not `PrimInt`, so they are encoded through their primitive type.
+macro_rules! impl_non_zero {
+    ($($nz:ty => $t:ty),*) => {
+        $(
+            /// Numbers that `new` approximates to zero get the closest non-zero value with the same sign,
+            /// so the sign survives the conversion back to the `NonZero` type.
+            impl<const SCALE: u8> From<$nz> for ScaledValue<$t, SCALE> {
+                fn from(number: $nz) -> Self {
+                    let value = Self::new(number.get());
+                    let closest = if number.get() < <$t>::default() {
+                        value.next_down()
+                    } else {
+                        value.next_up()
+                    };
+                    match closest {
+                        Some(closest) if value.approximate() == <$t>::default() => closest,
+                        _ => value,
+                    }
+                }
+            }
+
+            /// A value that approximates to zero (e.g. `new(1)`) becomes `1`,
+            /// or `-1` for a raw value with the sign flag, to keep the number non-zero.
+            impl<const SCALE: u8> From<ScaledValue<$t, SCALE>> for $nz {
+                fn from(value: ScaledValue<$t, SCALE>) -> Self {
+                    // the flag is never set for unsigned types
+                    let one: $t = if value.flag() { <$t>::default().wrapping_sub(1) } else { 1 };
+                    <$nz>::new(value.approximate())
+                        .or(<$nz>::new(one))
+                        .unwrap_or(<$nz>::MIN)
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero! {
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize
+}
@@ -0,0 +1,71 @@
+use crate::{ScaledValue, SpecialBytes};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use num_traits::Signed;
+
+// Operations with a plain number use it at full precision, only the result is encoded.
+macro_rules! impl_ops {
+    ($($op:ident::$method:ident, $assign:ident::$assign_method:ident);*) => {
+        $(
+            impl<T: SpecialBytes, const SCALE: u8> $op<T> for ScaledValue<T, SCALE> {
+                type Output = Self;
+
+                fn $method(self, rhs: T) -> Self::Output {
+                    Self::new(self.approximate().$method(rhs))
+                }
+            }
+
+            impl<T: SpecialBytes, const SCALE: u8> $assign for ScaledValue<T, SCALE> {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = (*self).$method(rhs);
+                }
+            }
+
+            impl<T: SpecialBytes, const SCALE: u8> $assign<T> for ScaledValue<T, SCALE> {
+                fn $assign_method(&mut self, rhs: T) {
+                    *self = (*self).$method(rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_ops! {
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+    Div::div, DivAssign::div_assign;
+    Rem::rem, RemAssign::rem_assign
+}
+
+// `T op SmallValue<T>` cannot be implemented for a generic `T`, so every primitive type gets its own impls.
+macro_rules! impl_primitive_ops {
+    ($($t:ty),*) => {
+        $(
+            impl_primitive_ops!(@op $t, Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem);
+        )*
+    };
+    (@op $t:ty, $($op:ident::$method:ident),*) => {
+        $(
+            impl<const SCALE: u8> $op<ScaledValue<$t, SCALE>> for $t {
+                type Output = ScaledValue<$t, SCALE>;
+
+                fn $method(self, rhs: ScaledValue<$t, SCALE>) -> Self::Output {
+                    ScaledValue::new(self.$method(rhs.approximate()))
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive_ops!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The encoding is symmetric, so only the sign flag changes and no precision is lost (zero stays zero).
+impl<T: SpecialBytes + Signed, const SCALE: u8> Neg for ScaledValue<T, SCALE> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_raw_unchecked(self.min_bits, self.percent, !self.flag).normalize()
+    }
+}
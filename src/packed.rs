@@ -0,0 +1,67 @@
+use crate::{ScaledValue, SmallValueError, SpecialBytes};
+
+// The sign bit is stored together with `min_bits`,
+// signed types never need more than 127 bits for the magnitude.
+const SIGN_MASK: u16 = 0b1000_0000;
+
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Packs the value into a single `u16`:
+    ///
+    /// ```text
+    ///  15            8 7 6           0
+    /// +---------------+-+-------------+
+    /// |    percent    |s|  min_bits   |   signed `T`
+    /// +---------------+-+-------------+
+    /// |    percent    |   min_bits    |   unsigned `T`
+    /// +---------------+---------------+
+    /// ```
+    ///
+    /// For signed types `min_bits` is at most 127, so the highest bit of the low byte is the sign.
+    /// Unsigned types have no sign, so the whole low byte is used for `min_bits` (up to 128 for `u128`).
+    /// ### Example
+    /// ```rust
+    /// let bits = SmallValue::new(128u32).to_bits();
+    /// assert_eq!(bits, 0x3F08);
+    /// ```
+    pub fn to_bits(&self) -> u16 {
+        let low = if self.flag {
+            u16::from(self.min_bits) | SIGN_MASK
+        } else {
+            u16::from(self.min_bits)
+        };
+        u16::from(self.percent) << 8 | low
+    }
+
+    /// Unpacks the value from a `u16` produced by `to_bits`.
+    /// Returns an error if the unpacked parts are not valid for `T`.
+    pub fn from_bits(bits: u16) -> Result<Self, SmallValueError> {
+        let percent = (bits >> 8) as u8;
+        let (min_bits, flag) = if Self::is_signed() {
+            ((bits & !SIGN_MASK & 0xFF) as u8, bits & SIGN_MASK != 0)
+        } else {
+            ((bits & 0xFF) as u8, false)
+        };
+
+        Self::try_from((min_bits, percent, flag))
+    }
+
+    /// Returns the packed value as a byte array in little-endian byte order.
+    pub fn to_le_bytes(&self) -> [u8; 2] {
+        self.to_bits().to_le_bytes()
+    }
+
+    /// Returns the packed value as a byte array in big-endian byte order.
+    pub fn to_be_bytes(&self) -> [u8; 2] {
+        self.to_bits().to_be_bytes()
+    }
+
+    /// Creates a value from its packed representation in little-endian byte order.
+    pub fn from_le_bytes(bytes: [u8; 2]) -> Result<Self, SmallValueError> {
+        Self::from_bits(u16::from_le_bytes(bytes))
+    }
+
+    /// Creates a value from its packed representation in big-endian byte order.
+    pub fn from_be_bytes(bytes: [u8; 2]) -> Result<Self, SmallValueError> {
+        Self::from_bits(u16::from_be_bytes(bytes))
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{ScaledValue, SpecialBytes};
+
+// The values are the canonical encodings in the order of `Ord`: negative numbers by decreasing absolute value,
+// zero and the positive numbers. Negative numbers have the same absolute values as positive ones.
+// The `*_reachable` variants skip the values that `new` never produces (their bucket is empty),
+// `new_with` and `try_from` can still create them.
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Returns the next greater value (in the order of `Ord`), `None` for the largest one.
+    ///
+    /// Every valid encoding is taken into account, so the bucket of the result can be empty
+    /// if `new` never produces it, `next_up_reachable` skips such values.
+    /// ### Example
+    /// ```rust
+    /// let value = SmallValue::new(1000u32);
+    /// let next = value.next_up().unwrap();
+    /// assert!(next > value);
+    /// ```
+    pub fn next_up(&self) -> Option<Self> {
+        let magnitude = self.representable_magnitude();
+        if !self.flag || magnitude == 0 {
+            let (min_bits, percent, _) = Self::next_magnitude(magnitude)?;
+            Some(Self::canonical(min_bits, percent, false))
+        } else {
+            let (min_bits, percent, _) = Self::prev_magnitude(magnitude - 1);
+            Some(Self::canonical(min_bits, percent, true))
+        }
+    }
+
+    /// Returns the next smaller value (in the order of `Ord`), `None` for the smallest one.
+    pub fn next_down(&self) -> Option<Self> {
+        let magnitude = self.representable_magnitude();
+        if self.flag || (magnitude == 0 && Self::is_signed()) {
+            let (min_bits, percent, _) = Self::next_magnitude(magnitude)?;
+            Some(Self::canonical(min_bits, percent, true))
+        } else {
+            let (min_bits, percent, _) = Self::prev_magnitude(magnitude.checked_sub(1)?);
+            Some(Self::canonical(min_bits, percent, false))
+        }
+    }
+
+    /// Returns an iterator over all canonical values of the type in ascending order,
+    /// from the smallest negative value (zero for unsigned types) to the largest value.
+    /// ### Example
+    /// ```rust
+    /// let table: Vec<u16> = SmallValue::<u16>::iter_all().map(|value| value.approximate()).collect();
+    /// assert_eq!(table.len(), SmallValue::<u16>::count_representable());
+    /// ```
+    pub fn iter_all() -> impl Iterator<Item = Self> {
+        let flag = Self::is_signed();
+        let (min_bits, percent, _) = Self::prev_magnitude(Self::unsigned_abs(T::max_value()));
+        let first = if flag {
+            Self::canonical(min_bits, percent, true)
+        } else {
+            Self::new(T::zero())
+        };
+        core::iter::successors(Some(first), Self::next_up)
+    }
+
+    /// Checks whether `new` produces this value for some number, i.e. `bucket` is not empty.
+    ///
+    /// Short bit lengths overlap the lower part of the next ones, so some valid encodings
+    /// are only created by `new_with`, `try_from` or `from_raw_unchecked`.
+    /// ### Example
+    /// ```rust
+    /// assert!(SmallValue::new(1000u32).is_reachable());
+    /// ```
+    pub fn is_reachable(&self) -> bool {
+        self.is_valid() && self.is_canonical() && Self::reachable(self.representable_magnitude())
+    }
+
+    /// Returns the next greater value that `new` produces (see `is_reachable`), `None` for the largest one.
+    pub fn next_up_reachable(&self) -> Option<Self> {
+        let mut value = self.next_up()?;
+        while !value.is_reachable() {
+            value = value.next_up()?;
+        }
+        Some(value)
+    }
+
+    /// Returns the next smaller value that `new` produces (see `is_reachable`), `None` for the smallest one.
+    pub fn next_down_reachable(&self) -> Option<Self> {
+        let mut value = self.next_down()?;
+        while !value.is_reachable() {
+            value = value.next_down()?;
+        }
+        Some(value)
+    }
+
+    /// Returns an iterator over the values that `new` produces in ascending order,
+    /// every number of `T` belongs to the bucket of exactly one of them.
+    /// ### Example
+    /// ```rust
+    /// let buckets = SmallValue::<u16>::iter_reachable().map(|value| value.bucket());
+    /// assert_eq!(buckets.map(|bucket| bucket.len()).sum::<usize>(), 1 << 16);
+    /// ```
+    pub fn iter_reachable() -> impl Iterator<Item = Self> {
+        // the smallest number is always in a bucket
+        let first = Self::new(T::min_value());
+        core::iter::successors(Some(first), Self::next_up_reachable)
+    }
+
+    /// Returns the number of canonical values of the type (the length of `iter_all`).
+    pub fn count_representable() -> usize {
+        let mut count = 1;
+        let mut magnitude = 0;
+        while let Some((_, _, next)) = Self::next_magnitude(magnitude) {
+            count += 1;
+            magnitude = next;
+        }
+
+        // every absolute value except zero is also negative
+        if Self::is_signed() {
+            count * 2 - 1
+        } else {
+            count
+        }
+    }
+
+    /// Returns the number of values that `new` produces (the length of `iter_reachable`).
+    pub fn count_reachable() -> usize {
+        let mut count = 1;
+        let mut magnitude = 0;
+        while let Some((_, _, next)) = Self::next_magnitude(magnitude) {
+            if Self::reachable(next) {
+                count += 1;
+            }
+            magnitude = next;
+        }
+
+        if Self::is_signed() {
+            count * 2 - 1
+        } else {
+            count
+        }
+    }
+
+    // `new` approximates the numbers of a bit length to its values below them, so an absolute value is reached
+    // either from the next number with the same bit length or from the smallest number of a longer one.
+    // The approximations of the smallest numbers grow with the bit length.
+    fn reachable(magnitude: u128) -> bool {
+        let approx = |number| {
+            let small_value = Self::encode(Self::from_magnitude(number, false));
+            Self::magnitude(small_value.min_bits, small_value.percent).unwrap_or_default()
+        };
+
+        let next = magnitude.checked_add(1);
+        if magnitude == 0
+            || next.is_some_and(|next| {
+                Self::bits_of(next) == Self::bits_of(magnitude) && approx(next) == magnitude
+            })
+        {
+            return true;
+        }
+
+        for min_bits in Self::bits_of(magnitude) + 1..=*Self::min_bits_range().end() {
+            let smallest = approx(1 << (min_bits - 1));
+            if smallest >= magnitude {
+                return smallest == magnitude;
+            }
+        }
+        false
+    }
+
+    fn representable_magnitude(&self) -> u128 {
+        Self::magnitude(self.min_bits, self.percent).unwrap_or_default()
+    }
+
+    fn canonical(min_bits: u8, percent: u8, flag: bool) -> Self {
+        Self::from_raw_unchecked(min_bits, percent, flag).normalize()
+    }
+}
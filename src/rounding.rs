@@ -0,0 +1,85 @@
+use crate::{ScaledValue, SpecialBytes};
+
+/// The rounding mode used by `ScaledValue::new_with`
+/// when the number cannot be represented exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards negative infinity, the approximation is never greater than the original.
+    Floor,
+    /// Round towards positive infinity, the approximation is never less than the original.
+    Ceil,
+    /// Round to the closest representable number, ties are rounded towards zero.
+    Nearest,
+    /// Round towards zero, the approximation is never further from zero than the original.
+    TowardZero,
+}
+
+// The rounding of the absolute value, the sign is stored separately.
+#[derive(Clone, Copy)]
+enum Direction {
+    Down,
+    Up,
+    Nearest,
+}
+
+impl Direction {
+    // The candidate with the smallest key wins:
+    // (breaks the guarantee, distance to the original, absolute value).
+    fn key(self, target: u128, magnitude: u128) -> (bool, u128, u128) {
+        let distance = target.abs_diff(magnitude);
+        match self {
+            Self::Down => (magnitude > target, distance, magnitude),
+            Self::Up => (magnitude < target, distance, magnitude),
+            Self::Nearest => (false, distance, magnitude),
+        }
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Create a new instance with an explicit rounding mode.
+    ///
+    /// Unlike `new`, the result gives a guarantee about `approximate()` compared to the original number:
+    ///
+    /// - `Rounding::Floor`: the largest representable number with `approximate() <= number`.
+    /// - `Rounding::Ceil`: the smallest representable number with `approximate() >= number`.
+    /// - `Rounding::Nearest`: `|approximate() - number|` is the smallest possible
+    ///   (ties are rounded towards zero).
+    /// - `Rounding::TowardZero`: the closest representable number with `|approximate()| <= |number|`,
+    ///   the sign is kept.
+    ///
+    /// The guarantee can be broken only when there is no representable number in the required direction,
+    /// then the closest representable number is used: numbers close to `T::MAX` with `Ceil`
+    /// and close to `T::MIN` with `Floor`.
+    ///
+    /// Every bit length is searched, so the number of bits can differ from `new`,
+    /// e.g. rounding up `63` with `Ceil` needs `7` bits.
+    /// ### Example
+    /// ```rust
+    /// let quota = SmallValue::new_with(1_000_000u64, Rounding::Ceil);
+    /// assert!(quota.approximate() >= 1_000_000);
+    /// ```
+    pub fn new_with(number: T, rounding: Rounding) -> Self {
+        let flag = number < T::zero();
+        let target = Self::unsigned_abs(number);
+        let direction = match (rounding, flag) {
+            (Rounding::Floor, false) | (Rounding::Ceil, true) | (Rounding::TowardZero, _) => {
+                Direction::Down
+            }
+            (Rounding::Ceil, false) | (Rounding::Floor, true) => Direction::Up,
+            (Rounding::Nearest, _) => Direction::Nearest,
+        };
+
+        // the closest representable absolute values below and above the target over all bit lengths
+        let below = Self::prev_magnitude(target);
+        let above = match target.checked_sub(1) {
+            Some(value) => Self::next_magnitude(value),
+            None => Some(below),
+        };
+
+        let (min_bits, percent, _) = match above {
+            Some(above) if direction.key(target, above.2) < direction.key(target, below.2) => above,
+            _ => below,
+        };
+        Self::from_raw_unchecked(min_bits, percent, flag).normalize()
+    }
+}
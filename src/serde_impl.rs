@@ -0,0 +1,271 @@
+use crate::{DynScaledValue, IntKind, ScaledValue, SpecialBytes};
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, SerializeTuple};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const FIELDS: &[&str] = &["min_bits", "percent", "flag", "approximate"];
+
+/// Human-readable formats (JSON, YAML, ...) get a struct with the raw parts
+/// and the decoded `approximate` value, binary formats get the packed `u16` from `to_bits`.
+impl<T: SpecialBytes + Serialize, const SCALE: u8> Serialize for ScaledValue<T, SCALE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("SmallValue", FIELDS.len())?;
+            state.serialize_field("min_bits", &self.min_bits)?;
+            state.serialize_field("percent", &self.percent)?;
+            state.serialize_field("flag", &self.flag)?;
+            state.serialize_field("approximate", &self.approximate())?;
+            state.end()
+        } else {
+            serializer.serialize_u16(self.to_bits())
+        }
+    }
+}
+
+/// The `approximate` field is only informational and is ignored,
+/// the raw parts are checked with `try_from`, so tuples that are invalid for `T` are rejected.
+impl<'de, T: SpecialBytes, const SCALE: u8> Deserialize<'de> for ScaledValue<T, SCALE> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_struct("SmallValue", FIELDS, SmallValueVisitor(PhantomData))
+        } else {
+            let bits = u16::deserialize(deserializer)?;
+            Self::from_bits(bits).map_err(de::Error::custom)
+        }
+    }
+}
+
+enum Field {
+    MinBits,
+    Percent,
+    Flag,
+    Approximate,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("`min_bits`, `percent`, `flag` or `approximate`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "min_bits" => Ok(Field::MinBits),
+                    "percent" => Ok(Field::Percent),
+                    "flag" => Ok(Field::Flag),
+                    "approximate" => Ok(Field::Approximate),
+                    other => Err(de::Error::unknown_field(other, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct SmallValueVisitor<T, const SCALE: u8>(PhantomData<T>);
+
+impl<T: SpecialBytes, const SCALE: u8> SmallValueVisitor<T, SCALE> {
+    fn build<E: de::Error>(
+        min_bits: u8,
+        percent: u8,
+        flag: bool,
+    ) -> Result<ScaledValue<T, SCALE>, E> {
+        ScaledValue::try_from((min_bits, percent, flag)).map_err(E::custom)
+    }
+}
+
+impl<'de, T: SpecialBytes, const SCALE: u8> Visitor<'de> for SmallValueVisitor<T, SCALE> {
+    type Value = ScaledValue<T, SCALE>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("struct SmallValue")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let min_bits = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let percent = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let flag = seq.next_element()?.unwrap_or_default();
+        // the decoded value is optional
+        seq.next_element::<IgnoredAny>()?;
+
+        Self::build(min_bits, percent, flag)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut min_bits = None;
+        let mut percent = None;
+        let mut flag = None;
+
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::MinBits => min_bits = Some(map.next_value()?),
+                Field::Percent => percent = Some(map.next_value()?),
+                Field::Flag => flag = Some(map.next_value()?),
+                Field::Approximate => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Self::build(
+            min_bits.ok_or_else(|| de::Error::missing_field("min_bits"))?,
+            percent.ok_or_else(|| de::Error::missing_field("percent"))?,
+            flag.unwrap_or_default(),
+        )
+    }
+}
+
+/// The type is stored by its `name`.
+impl Serialize for IntKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// The name is copied while it is read, so it does not have to be borrowed from the input.
+impl<'de> Deserialize<'de> for IntKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KindVisitor;
+
+        impl Visitor<'_> for KindVisitor {
+            type Value = IntKind;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an integer type")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<IntKind, E> {
+                IntKind::from_name(value)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_str(KindVisitor)
+    }
+}
+
+const DYN_FIELDS: &[&str] = &["kind", "min_bits", "percent", "flag"];
+
+/// The type is stored with the value: human-readable formats get a struct with the name of the type
+/// (`kind`) and the raw parts, binary formats get a tuple of `IntKind::code` and the packed `u16`.
+impl<const SCALE: u8> Serialize for DynScaledValue<SCALE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("DynSmallValue", DYN_FIELDS.len())?;
+            state.serialize_field("kind", &self.kind())?;
+            state.serialize_field("min_bits", &self.min_bits())?;
+            state.serialize_field("percent", &self.percent())?;
+            state.serialize_field("flag", &self.flag())?;
+            state.end()
+        } else {
+            let mut state = serializer.serialize_tuple(2)?;
+            state.serialize_element(&self.kind().code())?;
+            state.serialize_element(&self.to_bits())?;
+            state.end()
+        }
+    }
+}
+
+/// The raw parts are checked for the stored type.
+impl<'de, const SCALE: u8> Deserialize<'de> for DynScaledValue<SCALE> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_struct("DynSmallValue", DYN_FIELDS, DynVisitor)
+        } else {
+            let (code, bits) = <(u8, u16)>::deserialize(deserializer)?;
+            let kind = IntKind::from_code(code).ok_or_else(|| {
+                de::Error::invalid_value(de::Unexpected::Unsigned(code.into()), &"a type code")
+            })?;
+            Self::from_bits(kind, bits).map_err(de::Error::custom)
+        }
+    }
+}
+
+struct DynVisitor<const SCALE: u8>;
+
+impl<const SCALE: u8> DynVisitor<SCALE> {
+    fn build<E: de::Error>(
+        kind: IntKind,
+        min_bits: u8,
+        percent: u8,
+        flag: bool,
+    ) -> Result<DynScaledValue<SCALE>, E> {
+        DynScaledValue::new(kind, min_bits, percent, flag).map_err(E::custom)
+    }
+}
+
+impl<'de, const SCALE: u8> Visitor<'de> for DynVisitor<SCALE> {
+    type Value = DynScaledValue<SCALE>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("struct DynSmallValue")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut kind = None;
+        let mut min_bits = None;
+        let mut percent = None;
+        let mut flag = None;
+
+        while let Some(key) = map.next_key::<DynField>()? {
+            match key {
+                DynField::Kind => kind = Some(map.next_value()?),
+                DynField::MinBits => min_bits = Some(map.next_value()?),
+                DynField::Percent => percent = Some(map.next_value()?),
+                DynField::Flag => flag = Some(map.next_value()?),
+            }
+        }
+
+        Self::build(
+            kind.ok_or_else(|| de::Error::missing_field("kind"))?,
+            min_bits.ok_or_else(|| de::Error::missing_field("min_bits"))?,
+            percent.ok_or_else(|| de::Error::missing_field("percent"))?,
+            flag.unwrap_or_default(),
+        )
+    }
+}
+
+enum DynField {
+    Kind,
+    MinBits,
+    Percent,
+    Flag,
+}
+
+impl<'de> Deserialize<'de> for DynField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = DynField;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("`kind`, `min_bits`, `percent` or `flag`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<DynField, E> {
+                match value {
+                    "kind" => Ok(DynField::Kind),
+                    "min_bits" => Ok(DynField::MinBits),
+                    "percent" => Ok(DynField::Percent),
+                    "flag" => Ok(DynField::Flag),
+                    other => Err(de::Error::unknown_field(other, DYN_FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
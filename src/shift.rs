@@ -0,0 +1,81 @@
+use crate::{Rounding, ScaledValue, SpecialBytes};
+use core::ops::{Shl, ShlAssign, Shr, ShrAssign};
+
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    /// Checked shift left, multiplies the value by `2^rhs`.
+    /// Returns `None` if `rhs` is not less than the number of bits in `T`
+    /// or if the result does not fit in `T` (unlike the primitive integers, the bits are not discarded).
+    ///
+    /// The approximate value is shifted exactly and rounded to the closest representable number once,
+    /// for large numbers it is the same percentage with `min_bits + rhs` bits.
+    /// ### Example
+    /// ```rust
+    /// let kib = SmallValue::new(1500u64);
+    /// let bytes = kib.checked_shl(10).unwrap();
+    /// ```
+    pub fn checked_shl(&self, rhs: u32) -> Option<Self> {
+        if rhs >= u32::from(T::bits()) {
+            return None;
+        }
+
+        let magnitude = Self::magnitude(self.min_bits, self.percent).unwrap_or_default();
+        if magnitude != 0
+            && u32::from(Self::bits_of(magnitude)) + rhs > u32::from(*Self::min_bits_range().end())
+        {
+            return None;
+        }
+
+        Some(Self::shifted(magnitude << rhs, self.flag))
+    }
+
+    /// Checked shift right, divides the value by `2^rhs` (rounded down like for the primitive integers).
+    /// Returns `None` if `rhs` is not less than the number of bits in `T`.
+    pub fn checked_shr(&self, rhs: u32) -> Option<Self> {
+        if rhs >= u32::from(T::bits()) {
+            return None;
+        }
+
+        let magnitude = Self::magnitude(self.min_bits, self.percent).unwrap_or_default();
+        let mut shifted = magnitude >> rhs;
+        // negative numbers are rounded towards negative infinity
+        if self.flag && shifted << rhs != magnitude {
+            shifted += 1;
+        }
+
+        Some(Self::shifted(shifted, self.flag))
+    }
+
+    fn shifted(magnitude: u128, flag: bool) -> Self {
+        Self::new_with(Self::from_magnitude(magnitude, flag), Rounding::Nearest)
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> Shl<u32> for ScaledValue<T, SCALE> {
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.checked_shl(rhs)
+            .expect("attempt to shift left with overflow")
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> Shr<u32> for ScaledValue<T, SCALE> {
+    type Output = Self;
+
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.checked_shr(rhs)
+            .expect("attempt to shift right with overflow")
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> ShlAssign<u32> for ScaledValue<T, SCALE> {
+    fn shl_assign(&mut self, rhs: u32) {
+        *self = *self << rhs;
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> ShrAssign<u32> for ScaledValue<T, SCALE> {
+    fn shr_assign(&mut self, rhs: u32) {
+        *self = *self >> rhs;
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{ScaledValue, SmallValueError, SpecialBytes};
+
+// The key is the approximate value in big-endian byte order with the sign bit flipped,
+// so negative numbers come first.
+impl<T: SpecialBytes, const SCALE: u8> ScaledValue<T, SCALE> {
+    fn sort_number(&self) -> T {
+        self.normalize().approximate()
+    }
+
+    fn from_sort_number(number: T) -> Result<Self, SmallValueError> {
+        let flag = number < T::zero();
+        let magnitude = Self::unsigned_abs(number);
+
+        match Self::prev_magnitude(magnitude) {
+            (min_bits, percent, found) if found == magnitude => {
+                Ok(Self::from_raw_unchecked(min_bits, percent, flag).normalize())
+            }
+            _ => Err(SmallValueError::NotRepresentable),
+        }
+    }
+}
+
+macro_rules! impl_sort_key {
+    ($($t:ty),*) => {
+        $(
+            impl<const SCALE: u8> ScaledValue<$t, SCALE> {
+                /// Returns a key whose byte-wise (lexicographic) order is the order of the values,
+                /// so the keys can be compared without decoding, e.g. in a key-value store.
+                ///
+                /// The key has the size of the type, equal values have equal keys.
+                /// It is the approximate value, not the 16 bits of `to_bits`: the rank of the value would fit
+                /// in 2 bytes, but the bit lengths overlap, so the rank can only be found by counting all smaller
+                /// values, while this key is computed directly from the approximate value.
+                /// ### Example
+                /// ```rust
+                /// let a = SmallValue::new(-5000i32).to_sort_key();
+                /// let b = SmallValue::new(30i32).to_sort_key();
+                /// assert!(a < b);
+                /// ```
+                pub fn to_sort_key(&self) -> [u8; core::mem::size_of::<$t>()] {
+                    (self.sort_number() ^ <$t>::MIN).to_be_bytes()
+                }
+
+                /// Creates the value from a key of `to_sort_key`.
+                /// Returns an error if the key does not belong to any value.
+                pub fn from_sort_key(
+                    key: [u8; core::mem::size_of::<$t>()],
+                ) -> Result<Self, SmallValueError> {
+                    Self::from_sort_number(<$t>::from_be_bytes(key) ^ <$t>::MIN)
+                }
+            }
+        )*
+    };
+}
+
+impl_sort_key!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
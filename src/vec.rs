@@ -0,0 +1,330 @@
+use crate::{ScaledValue, SmallValueError, SpecialBytes};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::{Bound, RangeBounds};
+
+// Each element takes the two bytes of `to_le_bytes`, the sign is included for every type.
+const ELEMENT_SIZE: usize = 2;
+
+/// A growable array of encoded numbers, each element is stored in its packed 16-bit form (see `to_bits`),
+/// so it takes 2 bytes for every `T` and the whole array can be saved with `as_bytes`.
+///
+/// The elements are canonical encodings, `get` and `iter` return the approximate values.
+/// ### Example
+/// ```rust
+/// let mut sizes: SmallValueVec<u64> = SmallValueVec::new();
+/// sizes.push(1000);
+/// sizes.extend([5_000_000, 42]);
+/// let total: u64 = sizes.iter().sum();
+/// let copy = SmallValueVec::<u64>::from_bytes(sizes.as_bytes())?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScaledValueVec<T: SpecialBytes, const SCALE: u8> {
+    bytes: Vec<u8>,
+    _phantom: PhantomData<T>,
+}
+
+/// `ScaledValueVec` with the percentage scale of `SmallValue`.
+pub type SmallValueVec<T> = ScaledValueVec<T, 100>;
+
+/// A borrowed part of a `ScaledValueVec`, created with `as_slice` or `slice`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScaledValueSlice<'a, T: SpecialBytes, const SCALE: u8> {
+    bytes: &'a [u8],
+    _phantom: PhantomData<T>,
+}
+
+/// `ScaledValueSlice` with the percentage scale of `SmallValue`.
+pub type SmallValueSlice<'a, T> = ScaledValueSlice<'a, T, 100>;
+
+impl<T: SpecialBytes, const SCALE: u8> ScaledValueVec<T, SCALE> {
+    /// Creates an empty array.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates an empty array with space for at least `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(capacity * ELEMENT_SIZE),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates an array from the bytes of `as_bytes`.
+    /// Returns an error if the length is odd or an element is not valid for `T`,
+    /// the elements are stored in the canonical form.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SmallValueError> {
+        if !bytes.len().is_multiple_of(ELEMENT_SIZE) {
+            return Err(SmallValueError::InvalidByteLength { len: bytes.len() });
+        }
+
+        let mut vec = Self::with_capacity(bytes.len() / ELEMENT_SIZE);
+        for chunk in bytes.chunks_exact(ELEMENT_SIZE) {
+            let value = ScaledValue::from_le_bytes([chunk[0], chunk[1]])?;
+            vec.push_value(value);
+        }
+        Ok(vec)
+    }
+
+    /// Encodes the number with `new` and appends it.
+    pub fn push(&mut self, number: T) {
+        self.push_value(ScaledValue::new(number));
+    }
+
+    /// Appends an encoded value.
+    pub fn push_value(&mut self, value: ScaledValue<T, SCALE>) {
+        self.bytes
+            .extend_from_slice(&value.normalize().to_le_bytes());
+    }
+
+    /// Removes the last element and returns its approximate value, `None` if the array is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let value = self.as_slice().last_value()?;
+        self.bytes.truncate(self.bytes.len() - ELEMENT_SIZE);
+        Some(value.approximate())
+    }
+
+    /// Shortens the array to `len` elements, nothing happens if it is already shorter.
+    pub fn truncate(&mut self, len: usize) {
+        self.bytes.truncate(len.saturating_mul(ELEMENT_SIZE));
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Checks whether the array has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the approximate value of the element, `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns the encoded element, `None` if the index is out of bounds.
+    pub fn get_value(&self, index: usize) -> Option<ScaledValue<T, SCALE>> {
+        self.as_slice().get_value(index)
+    }
+
+    /// Replaces the element with the encoding of the number.
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn set(&mut self, index: usize, number: T) {
+        let len = self.len();
+        assert!(
+            index < len,
+            "index out of bounds: the len is {} but the index is {}",
+            len,
+            index
+        );
+        let bytes = ScaledValue::<T, SCALE>::new(number).to_le_bytes();
+        self.bytes[index * ELEMENT_SIZE..][..ELEMENT_SIZE].copy_from_slice(&bytes);
+    }
+
+    /// Returns an iterator over the approximate values.
+    pub fn iter(&self) -> ScaledValueVecIter<'_, T, SCALE> {
+        self.as_slice().iter()
+    }
+
+    /// Returns the whole array as a slice.
+    pub fn as_slice(&self) -> ScaledValueSlice<'_, T, SCALE> {
+        ScaledValueSlice {
+            bytes: &self.bytes,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the elements in the range as a slice.
+    ///
+    /// Panics if the range is out of bounds, like the indexing of a slice.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> ScaledValueSlice<'_, T, SCALE> {
+        self.as_slice().slice(range)
+    }
+
+    /// Returns the packed elements, two bytes in little-endian byte order for each one.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl<'a, T: SpecialBytes, const SCALE: u8> ScaledValueSlice<'a, T, SCALE> {
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.bytes.len() / ELEMENT_SIZE
+    }
+
+    /// Checks whether the slice has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the approximate value of the element, `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.get_value(index).map(|value| value.approximate())
+    }
+
+    /// Returns the encoded element, `None` if the index is out of bounds.
+    pub fn get_value(&self, index: usize) -> Option<ScaledValue<T, SCALE>> {
+        let start = index.checked_mul(ELEMENT_SIZE)?;
+        let chunk = self.bytes.get(start..start + ELEMENT_SIZE)?;
+        Some(Self::decode(chunk))
+    }
+
+    /// Returns an iterator over the approximate values.
+    pub fn iter(&self) -> ScaledValueVecIter<'a, T, SCALE> {
+        ScaledValueVecIter {
+            chunks: self.bytes.chunks_exact(ELEMENT_SIZE),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the elements in the range as a slice.
+    ///
+    /// Panics if the range is out of bounds, like the indexing of a slice.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+
+        Self {
+            bytes: &self.bytes[start * ELEMENT_SIZE..end * ELEMENT_SIZE],
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the packed elements, two bytes in little-endian byte order for each one.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Copies the elements into a new array.
+    pub fn to_vec(&self) -> ScaledValueVec<T, SCALE> {
+        ScaledValueVec {
+            bytes: self.bytes.to_vec(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn last_value(&self) -> Option<ScaledValue<T, SCALE>> {
+        self.len()
+            .checked_sub(1)
+            .and_then(|index| self.get_value(index))
+    }
+
+    // The bytes were checked when the element was added.
+    fn decode(chunk: &[u8]) -> ScaledValue<T, SCALE> {
+        let bits = u16::from_le_bytes([chunk[0], chunk[1]]);
+        ScaledValue::from_bits(bits).unwrap_or_default()
+    }
+}
+
+/// An iterator over the approximate values of a `ScaledValueVec` or `ScaledValueSlice`.
+#[derive(Debug, Clone)]
+pub struct ScaledValueVecIter<'a, T: SpecialBytes, const SCALE: u8> {
+    chunks: core::slice::ChunksExact<'a, u8>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: SpecialBytes, const SCALE: u8> Iterator for ScaledValueVecIter<'_, T, SCALE> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.chunks
+            .next()
+            .map(|chunk| ScaledValueSlice::<T, SCALE>::decode(chunk).approximate())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> DoubleEndedIterator for ScaledValueVecIter<'_, T, SCALE> {
+    fn next_back(&mut self) -> Option<T> {
+        self.chunks
+            .next_back()
+            .map(|chunk| ScaledValueSlice::<T, SCALE>::decode(chunk).approximate())
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> ExactSizeIterator for ScaledValueVecIter<'_, T, SCALE> {}
+
+impl<T: SpecialBytes, const SCALE: u8> Default for ScaledValueVec<T, SCALE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T: SpecialBytes, const SCALE: u8> IntoIterator for &'a ScaledValueVec<T, SCALE> {
+    type Item = T;
+    type IntoIter = ScaledValueVecIter<'a, T, SCALE>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: SpecialBytes, const SCALE: u8> IntoIterator for ScaledValueSlice<'a, T, SCALE> {
+    type Item = T;
+    type IntoIter = ScaledValueVecIter<'a, T, SCALE>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> Extend<T> for ScaledValueVec<T, SCALE> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for number in iter {
+            self.push(number);
+        }
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> Extend<ScaledValue<T, SCALE>> for ScaledValueVec<T, SCALE> {
+    fn extend<I: IntoIterator<Item = ScaledValue<T, SCALE>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_value(value);
+        }
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> FromIterator<T> for ScaledValueVec<T, SCALE> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> FromIterator<ScaledValue<T, SCALE>>
+    for ScaledValueVec<T, SCALE>
+{
+    fn from_iter<I: IntoIterator<Item = ScaledValue<T, SCALE>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T: SpecialBytes, const SCALE: u8> From<&[ScaledValue<T, SCALE>]> for ScaledValueVec<T, SCALE> {
+    fn from(values: &[ScaledValue<T, SCALE>]) -> Self {
+        values.iter().copied().collect()
+    }
+}
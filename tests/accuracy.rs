@@ -0,0 +1,120 @@
+use approx_int::{ScaledValue, SmallValue};
+
+#[test]
+fn accuracy_t_0() {
+    let small_value = SmallValue::new(1000u32);
+    let approx = small_value.approximate();
+    assert_eq!(
+        small_value.error_rate(1000),
+        f64::from(1000 - approx) / 1000.0 * 100.0
+    );
+    assert_eq!(small_value.error_rate(approx), 0.0);
+
+    let negative = SmallValue::new(-1000i32);
+    assert_eq!(negative.error_rate(-1000), small_value.error_rate(1000));
+    // the distance across zero
+    assert_eq!(negative.error_rate(1000), 100.0 + f64::from(approx) / 10.0);
+
+    assert_eq!(SmallValue::new(0u8).error_rate(0), 0.0);
+    assert_eq!(small_value.error_rate(0), f64::INFINITY);
+    assert_eq!(SmallValue::new(1u8).error_rate(1), 100.0);
+}
+
+#[test]
+fn accuracy_t_1() {
+    let small_value = SmallValue::new(u128::MAX);
+    let error = small_value.error_rate(u128::MAX);
+    assert!(error > 0.0 && error < 2.0);
+
+    let small_value = SmallValue::new(i128::MIN);
+    let error = small_value.error_rate(i128::MIN);
+    assert!(error > 0.0 && error < 2.0);
+}
+
+#[test]
+fn accuracy_t_2() {
+    let mut value = i16::MIN;
+    loop {
+        let small_value = SmallValue::new(value);
+        let bucket = small_value.bucket();
+        let max_error = small_value.max_relative_error();
+        let worst = bucket
+            .clone()
+            .map(|number| small_value.error_rate(number))
+            .fold(0.0, f64::max);
+        assert_eq!(max_error, worst);
+
+        if *bucket.end() == i16::MAX {
+            break;
+        }
+        value = *bucket.end() + 1;
+    }
+}
+
+#[test]
+fn accuracy_t_3() {
+    let mut worst = [0.0f64; 17];
+    for value in (0..=u16::MAX).rev() {
+        let error = SmallValue::new(value).error_rate(value);
+        let bits = (16 - value.leading_zeros()) as usize;
+        worst[bits] = worst[bits].max(error);
+    }
+    // the worst case of all numbers from `2^(bits - 1)` on
+    for bits in 1..=16 {
+        let from_bits = worst[bits..].iter().copied().fold(0.0, f64::max);
+        assert_eq!(
+            SmallValue::<u16>::worst_case_relative_error_from(1 << (bits - 1)),
+            from_bits
+        );
+    }
+    assert_eq!(
+        SmallValue::<u16>::worst_case_relative_error(),
+        Some(
+            (100..=u16::MAX)
+                .map(|value| SmallValue::new(value).error_rate(value))
+                .fold(0.0, f64::max)
+        )
+    );
+    assert_eq!(SmallValue::<u16>::worst_case_relative_error_from(0), 100.0);
+}
+
+#[test]
+fn accuracy_t_4() {
+    for from in [0, 1, 2, 3, 50, 99, 100, 127, 128, 1000, 20_000] {
+        let worst = (from..=i16::MAX)
+            .chain(i16::MIN..=-from)
+            .map(|value| SmallValue::new(value).error_rate(value))
+            .fold(0.0, f64::max);
+        assert_eq!(
+            SmallValue::<i16>::worst_case_relative_error_from(from),
+            worst
+        );
+        assert_eq!(
+            SmallValue::<i16>::worst_case_relative_error_from(-from),
+            worst
+        );
+    }
+}
+
+#[test]
+fn accuracy_t_5() {
+    assert!(SmallValue::<u64>::worst_case_relative_error_from(10_000) < 2.0);
+    assert!(SmallValue::<i128>::worst_case_relative_error_from(10_000) < 2.0);
+    // more steps give a smaller error
+    assert!(
+        ScaledValue::<u64, 255>::worst_case_relative_error_from(1 << 20)
+            < SmallValue::<u64>::worst_case_relative_error_from(1 << 20)
+    );
+    assert_eq!(
+        SmallValue::<u8>::worst_case_relative_error(),
+        Some(SmallValue::<u8>::worst_case_relative_error_from(100))
+    );
+    assert!(SmallValue::<u32>::worst_case_relative_error().is_some_and(|error| error < 25.0));
+    // no number of `i8` reaches `SCALE`
+    assert_eq!(ScaledValue::<i8, 255>::worst_case_relative_error(), None);
+    // only `i8::MIN`, which is encoded like `-i8::MAX`
+    assert_eq!(
+        ScaledValue::<i8, 128>::worst_case_relative_error(),
+        Some(ScaledValue::<i8, 128>::new(i8::MIN).error_rate(i8::MIN))
+    );
+}
@@ -0,0 +1,83 @@
+use approx_int::{SmallValue, SmallValueError};
+
+#[test]
+fn bits_t_0() {
+    let small_value = SmallValue::new(128u32);
+    assert_eq!(small_value.to_bits(), 0x3F08);
+    assert_eq!(SmallValue::from_bits(0x3F08), Ok(small_value));
+}
+
+#[test]
+fn bits_t_1() {
+    let small_value = SmallValue::new(u128::MAX);
+    assert_eq!(small_value.to_bits() & 0xFF, 128);
+    assert_eq!(
+        SmallValue::from_bits(small_value.to_bits()),
+        Ok(small_value)
+    );
+}
+
+#[test]
+fn bits_t_2() {
+    for value in [i128::MIN, i128::MIN + 1, -1, 0, 1, i128::MAX] {
+        let small_value = SmallValue::new(value);
+        assert_eq!(
+            SmallValue::from_bits(small_value.to_bits()),
+            Ok(small_value)
+        );
+    }
+}
+
+#[test]
+fn bits_t_3() {
+    for value in -100_000i32..100_000 {
+        let small_value = SmallValue::new(value);
+        assert_eq!(
+            SmallValue::from_bits(small_value.to_bits()),
+            Ok(small_value)
+        );
+    }
+}
+
+#[test]
+fn bits_t_4() {
+    let small_value = SmallValue::new(-128i64);
+    assert_ne!(small_value.to_bits() & 0x80, 0);
+}
+
+#[test]
+fn bits_t_5() {
+    assert_eq!(
+        SmallValue::<u32>::from_bits(0x3F21),
+        Err(SmallValueError::MinBitsOutOfRange {
+            min_bits: 33,
+            min: 1,
+            max: 32
+        })
+    );
+}
+
+#[test]
+fn bits_t_6() {
+    assert!(SmallValue::<u64>::from_bits(0x6408).is_err());
+}
+
+#[test]
+fn bytes_t_0() {
+    let small_value = SmallValue::new(-3200311i32);
+    assert_eq!(
+        SmallValue::from_le_bytes(small_value.to_le_bytes()),
+        Ok(small_value)
+    );
+    assert_eq!(
+        SmallValue::from_be_bytes(small_value.to_be_bytes()),
+        Ok(small_value)
+    );
+}
+
+#[test]
+fn bytes_t_1() {
+    let small_value = SmallValue::new(128u64);
+    assert_eq!(small_value.to_le_bytes(), [8, 63]);
+    assert_eq!(small_value.to_be_bytes(), [63, 8]);
+}
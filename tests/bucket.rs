@@ -0,0 +1,138 @@
+use approx_int::{ScaledValue, SmallValue};
+use std::collections::HashMap;
+
+#[test]
+fn bucket_t_0() {
+    let mut buckets: HashMap<(u8, u8, bool), (u16, u16)> = HashMap::new();
+    for value in u16::MIN..=u16::MAX {
+        let small_value = SmallValue::new(value);
+        assert!(small_value.bucket().contains(&value));
+        assert!(small_value.contains(value));
+        let bucket = buckets.entry(small_value.into()).or_insert((value, value));
+        bucket.1 = value;
+    }
+    for (tuple, (start, end)) in buckets {
+        let small_value = SmallValue::<u16>::try_from(tuple).unwrap();
+        assert_eq!(small_value.bucket(), start..=end);
+    }
+}
+
+#[test]
+fn bucket_t_1() {
+    let mut buckets: HashMap<(u8, u8, bool), (i16, i16)> = HashMap::new();
+    for value in i16::MIN..=i16::MAX {
+        let small_value = SmallValue::new(value);
+        assert!(small_value.bucket().contains(&value));
+        assert!(small_value.contains(value));
+        let bucket = buckets.entry(small_value.into()).or_insert((value, value));
+        bucket.1 = value;
+    }
+    for (tuple, (start, end)) in buckets {
+        let small_value = SmallValue::<i16>::try_from(tuple).unwrap();
+        assert_eq!(small_value.bucket(), start..=end);
+    }
+    // `i16::MIN` is encoded like `-i16::MAX`
+    assert_eq!(*SmallValue::new(i16::MIN).bucket().start(), i16::MIN);
+    // there is a single zero for both signs
+    assert_eq!(SmallValue::new(0i16).bucket(), -1..=1);
+    assert!(SmallValue::<i16>::from_raw_unchecked(1, 1, true)
+        .bucket()
+        .is_empty());
+}
+
+#[test]
+fn bucket_t_2() {
+    let mut value = u64::MAX;
+    while value > 0 {
+        let small_value = SmallValue::new(value);
+        let bucket = small_value.bucket();
+        assert!(bucket.contains(&value));
+        assert!(small_value.contains(*bucket.start()));
+        assert!(small_value.contains(*bucket.end()));
+        if *bucket.start() > 0 {
+            assert!(!small_value.contains(bucket.start() - 1));
+        }
+        if *bucket.end() < u64::MAX {
+            assert!(!small_value.contains(bucket.end() + 1));
+        }
+        value = value / 5 * 4;
+    }
+}
+
+#[test]
+fn bucket_t_3() {
+    assert_eq!(*SmallValue::new(u64::MAX).bucket().end(), u64::MAX);
+    assert_eq!(*SmallValue::new(u128::MAX).bucket().end(), u128::MAX);
+    assert_eq!(*SmallValue::new(i64::MAX).bucket().end(), i64::MAX);
+    assert_eq!(
+        *ScaledValue::<u32, 255>::new(u32::MAX).bucket().end(),
+        u32::MAX
+    );
+    // `1` also approximates to zero
+    assert_eq!(SmallValue::new(0u32).bucket(), 0..=1);
+}
+
+#[test]
+fn bucket_t_4() {
+    // never produced by `new`
+    let small_value = SmallValue::<u32>::from_raw_unchecked(1, 50, false);
+    assert!(small_value.bucket().is_empty());
+    let small_value = SmallValue::<u32>::try_from((20, 1)).unwrap();
+    assert!(small_value.bucket().is_empty());
+    assert!(SmallValue::<u32>::from_raw_unchecked(40, 50, false)
+        .bucket()
+        .is_empty());
+}
+
+#[test]
+fn bucket_t_5() {
+    let mut value = i64::MIN;
+    while value < 0 {
+        let small_value = SmallValue::new(value);
+        let bucket = small_value.bucket();
+        assert!(bucket.contains(&value));
+        if *bucket.start() > i64::MIN {
+            assert!(!small_value.contains(bucket.start() - 1));
+        }
+        assert!(!small_value.contains(bucket.end() + 1));
+        value = value / 5 * 4;
+    }
+}
+
+// every number is in the bucket of its value, which is the exact range of the numbers with this value
+fn check_exhaustive<T, const SCALE: u8>(numbers: impl Iterator<Item = T>)
+where
+    T: approx_int::SpecialBytes + std::hash::Hash + std::fmt::Debug,
+{
+    let mut buckets: HashMap<ScaledValue<T, SCALE>, (T, T)> = HashMap::new();
+    for number in numbers {
+        let bucket = buckets
+            .entry(ScaledValue::new(number))
+            .or_insert((number, number));
+        bucket.1 = number;
+    }
+    for (small_value, (start, end)) in buckets {
+        assert_eq!(small_value.bucket(), start..=end, "{small_value:?}");
+        let interval = small_value.interval();
+        assert!(interval.min() <= start && end <= interval.max());
+    }
+}
+
+#[test]
+fn bucket_t_6() {
+    fn check<const SCALE: u8>() {
+        check_exhaustive::<u16, SCALE>(u16::MIN..=u16::MAX);
+        check_exhaustive::<i16, SCALE>(i16::MIN..=i16::MAX);
+    }
+    check::<2>();
+    check::<3>();
+    check::<7>();
+    check::<128>();
+    check::<255>();
+
+    // `256` is encoded with 9 bits, but the canonical value has 8
+    let small_value = ScaledValue::<u16, 255>::new(256);
+    assert_eq!(small_value.min_bits(), 8);
+    assert!(small_value.bucket().contains(&256));
+    assert!(small_value.interval().contains(256));
+}
@@ -0,0 +1,139 @@
+use approx_int::{ScaledValue, SmallValue, SpecialBytes};
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{Hash, Hasher};
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn raw_values() -> Vec<SmallValue<u8>> {
+    let mut values = Vec::new();
+    for min_bits in 1..=8 {
+        for percent in 1..100 {
+            values.push(SmallValue::from_raw_unchecked(min_bits, percent, false));
+        }
+    }
+    values
+}
+
+#[test]
+fn canonical_t_0() {
+    let values = raw_values();
+    for a in &values {
+        for b in &values {
+            assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+            assert_eq!(a == b, a.approximate() == b.approximate());
+            if a == b {
+                assert_eq!(hash_of(a), hash_of(b));
+            }
+        }
+    }
+}
+
+#[test]
+fn canonical_t_1() {
+    for small_value in raw_values() {
+        let canonical = small_value.normalize();
+        assert!(canonical.is_canonical());
+        assert_eq!(canonical.approximate(), small_value.approximate());
+        assert_eq!(canonical.normalize(), canonical);
+        let raw: (u8, u8, bool) = canonical.into();
+        assert_eq!(raw, canonical.normalize().into());
+    }
+}
+
+#[test]
+fn canonical_t_2() {
+    let values = raw_values();
+    let approximations: HashSet<u8> = values.iter().map(|value| value.approximate()).collect();
+    let hashed: HashSet<SmallValue<u8>> = values.iter().copied().collect();
+    let ordered: BTreeSet<SmallValue<u8>> = values.iter().copied().collect();
+    assert_eq!(hashed.len(), approximations.len());
+    assert_eq!(ordered.len(), approximations.len());
+
+    let mut sorted = values.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), approximations.len());
+}
+
+#[test]
+fn canonical_t_3() {
+    for value in i16::MIN..=i16::MAX {
+        assert!(SmallValue::new(value).is_canonical());
+        assert!(ScaledValue::<i16, 7>::new(value).is_canonical());
+    }
+}
+
+#[test]
+fn canonical_t_4() {
+    let small_value = SmallValue::<u32>::try_from((1, 99)).unwrap();
+    let raw: (u8, u8, bool) = small_value.into();
+    assert_eq!(raw, (1, 1, false));
+    assert_eq!(small_value, SmallValue::new(0));
+    assert_eq!(SmallValue::new(1u32), SmallValue::new(0));
+}
+
+#[test]
+fn canonical_t_5() {
+    // small negative numbers approximate to the same zero
+    let zero = SmallValue::new(-1i32);
+    assert_eq!(zero.approximate(), 0);
+    assert!(!zero.flag());
+    assert_eq!(zero, SmallValue::new(0));
+    assert_eq!(zero.cmp(&SmallValue::new(0)), core::cmp::Ordering::Equal);
+    assert_eq!(
+        SmallValue::<i32>::from_raw_unchecked(1, 50, true).normalize(),
+        zero
+    );
+    assert!(SmallValue::new(-100) < zero);
+}
+
+#[test]
+fn canonical_t_6() {
+    let a = SmallValue::new(7u64);
+    let b = SmallValue::new(3u64);
+    assert!((a + b).is_canonical());
+    assert!((a - b).is_canonical());
+    assert!((a * b).is_canonical());
+    assert!((a / b).is_canonical());
+    assert!(a.checked_rem(b).unwrap().is_canonical());
+}
+
+// `Eq`, `Hash` and `Ord` of every raw tuple agree with its normalized value
+fn check_raw<T: SpecialBytes + std::fmt::Debug, const SCALE: u8>() {
+    let hash = |value: &ScaledValue<T, SCALE>| {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    };
+    for min_bits in 0..=T::bits() + 1 {
+        for percent in 0..=SCALE {
+            for flag in [false, true] {
+                let value = ScaledValue::<T, SCALE>::from_raw_unchecked(min_bits, percent, flag);
+                let canonical = value.normalize();
+                let raw: (u8, u8, bool) = value.into();
+                assert_eq!(value.is_canonical(), raw == canonical.into(), "{raw:?}");
+                assert_eq!(value, canonical);
+                assert_eq!(value.cmp(&canonical), Ordering::Equal);
+                assert_eq!(hash(&value), hash(&canonical));
+            }
+        }
+    }
+}
+
+#[test]
+fn canonical_t_7() {
+    check_raw::<u8, 100>();
+    check_raw::<i8, 100>();
+    check_raw::<u16, 100>();
+    check_raw::<i16, 3>();
+    check_raw::<u32, 2>();
+    check_raw::<i32, 7>();
+    check_raw::<u64, 255>();
+    check_raw::<i128, 100>();
+}
@@ -0,0 +1,64 @@
+use approx_int::{ScaledValue, SmallValue, SmallValueError};
+
+#[test]
+fn convert_t_0() {
+    for value in u16::MIN..=u16::MAX {
+        let small_value = SmallValue::new(value);
+        let approx = small_value.approximate();
+        assert_eq!(
+            SmallValue::<u32>::from(small_value).approximate(),
+            u32::from(approx)
+        );
+        assert_eq!(
+            SmallValue::<u128>::from(small_value).approximate(),
+            u128::from(approx)
+        );
+        assert_eq!(
+            SmallValue::<i32>::from(small_value).approximate(),
+            i32::from(approx)
+        );
+        assert!(SmallValue::<u64>::from(small_value).is_canonical());
+    }
+}
+
+#[test]
+fn convert_t_1() {
+    for value in i16::MIN..=i16::MAX {
+        let small_value = ScaledValue::<i16, 255>::new(value);
+        let approx = small_value.approximate();
+        let wide = ScaledValue::<i64, 255>::from(small_value);
+        assert_eq!(wide.approximate(), i64::from(approx));
+        assert_eq!(ScaledValue::<i16, 255>::try_from(wide), Ok(small_value));
+    }
+}
+
+#[test]
+fn convert_t_2() {
+    let small_value = SmallValue::new(u32::MAX);
+    let wide = SmallValue::<u64>::from(small_value);
+    assert_eq!(wide.approximate(), u64::from(small_value.approximate()));
+    assert_eq!(SmallValue::<u32>::try_from(wide), Ok(small_value));
+    assert!(matches!(
+        SmallValue::<i32>::try_from(wide),
+        Err(SmallValueError::MinBitsOutOfRange { min_bits: 32, .. })
+    ));
+    assert!(matches!(
+        SmallValue::<u32>::try_from(SmallValue::new(1u64 << 40)),
+        Err(SmallValueError::MinBitsOutOfRange { min_bits: 41, .. })
+    ));
+}
+
+#[test]
+fn convert_t_3() {
+    assert_eq!(
+        SmallValue::<u32>::try_from(SmallValue::new(-1000i64)),
+        Err(SmallValueError::NegativeUnsigned)
+    );
+    let small_value = SmallValue::<u8>::try_from(SmallValue::new(200i64)).unwrap();
+    assert_eq!(small_value.approximate(), 198);
+    let small_value = SmallValue::<usize>::try_from(SmallValue::new(5000u64)).unwrap();
+    assert_eq!(
+        small_value.approximate() as u64,
+        SmallValue::new(5000u64).approximate()
+    );
+}
@@ -0,0 +1,157 @@
+use approx_int::{ApproxCounter, ScaledValue, SmallValue};
+use std::collections::BTreeSet;
+
+fn xorshift(seed: u64) -> impl FnMut() -> u64 {
+    let mut state = seed;
+    move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    }
+}
+
+#[test]
+fn counter_t_0() {
+    let mut rng = xorshift(1);
+    let mut counter = ApproxCounter::<u32>::new();
+    assert_eq!(counter.estimate(), 0);
+    // the steps are exact for small numbers
+    for count in 1..=64 {
+        counter.increment(&mut rng);
+        assert_eq!(counter.estimate(), count);
+    }
+    assert_eq!(counter.confidence_interval(3).width(), 0);
+}
+
+#[test]
+fn counter_t_1() {
+    let mut rng = xorshift(2);
+    let (trials, count) = (200, 5000u64);
+    let mut sum = 0;
+    let mut inside = 0;
+    for _ in 0..trials {
+        let mut counter = ApproxCounter::<u64>::new();
+        for _ in 0..count {
+            counter.increment(&mut rng);
+        }
+        sum += counter.estimate();
+        if counter.confidence_interval(2).contains(count) {
+            inside += 1;
+        }
+    }
+
+    let mean = sum / trials;
+    assert!(mean.abs_diff(count) < count / 50, "mean {}", mean);
+    assert!(inside >= trials * 85 / 100, "inside {}", inside);
+}
+
+#[test]
+fn counter_t_2() {
+    let mut rng = xorshift(3);
+    let (trials, count) = (500, 1_000_000u64);
+    let mut sum = 0;
+    for _ in 0..trials {
+        let mut counter = ApproxCounter::<u64>::new();
+        for _ in 0..10 {
+            counter.increment_by(count / 10, &mut rng);
+        }
+        sum += counter.estimate();
+    }
+    let mean = sum / trials;
+    assert!(mean.abs_diff(count) < count / 100, "mean {}", mean);
+
+    // a representable target is reached exactly
+    let mut counter = ApproxCounter::<u64>::new();
+    let target = SmallValue::new(1_000_000u64).approximate();
+    counter.increment_by(target, &mut rng);
+    assert_eq!(counter.estimate(), target);
+    counter.increment_by(0, &mut rng);
+    assert_eq!(counter.estimate(), target);
+}
+
+#[test]
+fn counter_t_3() {
+    let mut rng = xorshift(4);
+    let (trials, count) = (500, 20_000u64);
+    let mut sum = 0;
+    for _ in 0..trials {
+        let mut a = ApproxCounter::<u64>::new();
+        let mut b = ApproxCounter::<u64>::new();
+        a.increment_by(count / 4, &mut rng);
+        b.increment_by(count * 3 / 4, &mut rng);
+        a.merge(&b, &mut rng);
+        sum += a.estimate();
+    }
+    let mean = sum / trials;
+    assert!(mean.abs_diff(count) < count / 100, "mean {}", mean);
+}
+
+#[test]
+fn counter_t_4() {
+    let mut rng = xorshift(5);
+    let mut counter = ApproxCounter::<u8>::new();
+    for _ in 0..10_000 {
+        counter.increment(&mut rng);
+    }
+    // stops at the largest representable value
+    assert_eq!(counter.estimate(), SmallValue::new(u8::MAX).approximate());
+    assert!(counter.confidence_interval(100).contains(u8::MAX));
+
+    let mut counter = ApproxCounter::<i16>::new();
+    counter.increment_by(12_345, &mut rng);
+    counter.increment_by(-100, &mut rng);
+    let bits = counter.value().to_bits();
+    let restored = ApproxCounter::from_value(SmallValue::<i16>::from_bits(bits).unwrap());
+    assert_eq!(restored, counter);
+    assert_eq!(
+        ApproxCounter::from_value(SmallValue::new(-500i16)).estimate(),
+        0
+    );
+    assert_eq!(ApproxCounter::<i16>::default().estimate(), 0);
+}
+
+#[test]
+fn counter_t_5() {
+    // with `0` every increment moves to the next representable value
+    fn all_steps<T: approx_int::SpecialBytes + std::fmt::Debug, const SCALE: u8>() {
+        let mut expected = BTreeSet::new();
+        for min_bits in 1..=T::bits() {
+            for percent in 1..SCALE {
+                if let Ok(value) = ScaledValue::<T, SCALE>::try_from((min_bits, percent)) {
+                    expected.insert(value.approximate());
+                }
+            }
+        }
+
+        let mut counter = ApproxCounter::<T, SCALE>::new();
+        let mut steps = vec![counter.estimate()];
+        loop {
+            counter.increment(|| 0);
+            if counter.estimate() == *steps.last().unwrap() {
+                break;
+            }
+            assert!(counter.value().is_canonical());
+            steps.push(counter.estimate());
+        }
+        assert_eq!(steps, expected.into_iter().collect::<Vec<_>>());
+    }
+
+    all_steps::<u16, 100>();
+    all_steps::<i16, 100>();
+    all_steps::<u32, 255>();
+    all_steps::<i8, 7>();
+    all_steps::<u16, 3>();
+    all_steps::<i32, 2>();
+    all_steps::<u64, 128>();
+}
+
+#[test]
+fn counter_t_6() {
+    // with `u64::MAX` the counter moves only over the steps of one
+    let mut counter = ApproxCounter::<u32>::new();
+    for _ in 0..100_000 {
+        counter.increment(|| u64::MAX);
+    }
+    assert!(counter.estimate() < 1000);
+}
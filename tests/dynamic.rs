@@ -0,0 +1,114 @@
+use approx_int::{DynSmallValue, IntKind, ScaledValue, SmallValue, SmallValueError};
+
+#[test]
+fn dynamic_t_0() {
+    let column: Vec<DynSmallValue> = vec![
+        SmallValue::new(1000u32).into(),
+        SmallValue::new(-5000i64).into(),
+        SmallValue::new(u128::MAX).into(),
+        SmallValue::new(i8::MIN).into(),
+    ];
+
+    assert_eq!(column[0].kind(), IntKind::U32);
+    let approx = SmallValue::new(1000u32).approximate();
+    assert_eq!(column[0].to_u128(), Some(u128::from(approx)));
+    assert_eq!(column[0].to_i128(), Some(i128::from(approx)));
+    assert_eq!(column[0].to_f64(), f64::from(approx));
+
+    assert_eq!(column[1].kind(), IntKind::I64);
+    assert_eq!(column[1].to_u128(), None);
+    let approx = SmallValue::new(-5000i64).approximate();
+    assert_eq!(column[1].to_i128(), Some(i128::from(approx)));
+    assert_eq!(column[1].to_f64(), approx as f64);
+
+    let max = SmallValue::new(u128::MAX).approximate();
+    assert_eq!(column[2].to_u128(), Some(max));
+    assert_eq!(column[2].to_i128(), None);
+    assert_eq!(column[2].to_f64(), max as f64);
+
+    assert_eq!(
+        column[3].to_i128(),
+        Some(i128::from(SmallValue::new(i8::MIN).approximate()))
+    );
+}
+
+#[test]
+fn dynamic_t_1() {
+    for value in i16::MIN..=i16::MAX {
+        let small_value = SmallValue::new(value);
+        let dyn_value = DynSmallValue::from(small_value);
+        assert_eq!(
+            dyn_value.to_i128(),
+            Some(i128::from(small_value.approximate()))
+        );
+        assert_eq!(SmallValue::<i16>::try_from(dyn_value), Ok(small_value));
+        assert_eq!(
+            SmallValue::<i64>::try_from(dyn_value).map(|v| v.approximate()),
+            Ok(i64::from(small_value.approximate()))
+        );
+    }
+}
+
+#[test]
+fn dynamic_t_2() {
+    let negative = DynSmallValue::from(SmallValue::new(-100i32));
+    assert!(SmallValue::<u64>::try_from(negative).is_err());
+
+    let big = DynSmallValue::from(SmallValue::new(u64::MAX));
+    assert!(matches!(
+        SmallValue::<u32>::try_from(big),
+        Err(SmallValueError::MinBitsOutOfRange { .. })
+    ));
+    assert_eq!(
+        SmallValue::<u128>::try_from(big).map(|v| v.approximate()),
+        Ok(u128::from(SmallValue::new(u64::MAX).approximate()))
+    );
+
+    let value = DynSmallValue::from(SmallValue::new(300usize));
+    assert_eq!(
+        SmallValue::<usize>::try_from(value),
+        Ok(SmallValue::new(300usize))
+    );
+}
+
+#[test]
+fn dynamic_t_3() {
+    assert_eq!(
+        DynSmallValue::new(IntKind::U8, 8, 50, false).map(|v| v.to_u128()),
+        SmallValue::<u8>::try_from((8, 50)).map(|v| Some(u128::from(v.approximate())))
+    );
+    assert!(DynSmallValue::new(IntKind::U8, 9, 50, false).is_err());
+    assert!(DynSmallValue::new(IntKind::U8, 8, 50, true).is_err());
+    assert!(DynSmallValue::new(IntKind::I8, 7, 50, true).is_ok());
+
+    let value = DynSmallValue::from(SmallValue::new(-123_456i32));
+    assert_eq!(
+        <(u8, u8, bool)>::from(value),
+        SmallValue::new(-123_456i32).into()
+    );
+    assert_eq!(
+        DynSmallValue::from_bits(value.kind(), value.to_bits()),
+        Ok(value)
+    );
+    assert_eq!(value.to_bits(), SmallValue::new(-123_456i32).to_bits());
+}
+
+#[test]
+fn dynamic_t_4() {
+    for code in 0..=u8::MAX {
+        match IntKind::from_code(code) {
+            Some(kind) => {
+                assert_eq!(kind.code(), code);
+                assert_eq!(IntKind::from_name(kind.name()), Some(kind));
+                assert_eq!(kind.to_string(), kind.name());
+            }
+            None => assert!(code >= 10),
+        }
+    }
+    assert_eq!(IntKind::from_name("usize"), None);
+
+    let value: ScaledValue<u32, 50> = ScaledValue::new(1000);
+    let dyn_value = approx_int::DynScaledValue::from(value);
+    assert_eq!(dyn_value.to_u128(), Some(u128::from(value.approximate())));
+    assert_eq!(ScaledValue::<u32, 50>::try_from(dyn_value), Ok(value));
+}
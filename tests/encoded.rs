@@ -0,0 +1,99 @@
+use approx_int::{ScaledValue, SmallValue};
+
+#[test]
+fn encoded_t_0() {
+    let values = [0u32, 1, 7, 99, 1000, 65_535, 123_456, u32::MAX];
+    for a in values.map(SmallValue::new) {
+        for b in values.map(SmallValue::new) {
+            assert_eq!(a.mul_encoded(b), a.checked_mul(b));
+            assert_eq!(a.div_encoded(b), a.checked_div(b));
+        }
+    }
+}
+
+#[test]
+fn encoded_t_1() {
+    let values = [-100_000i64, -1000, -3, 0, 5, 4096, i64::MAX, i64::MIN];
+    for a in values.map(SmallValue::new) {
+        for b in values.map(SmallValue::new) {
+            assert_eq!(a.mul_encoded(b), a.checked_mul(b));
+            assert_eq!(a.div_encoded(b), a.checked_div(b));
+        }
+    }
+}
+
+#[test]
+fn encoded_t_2() {
+    // the product of `u128` values overflows only if it does not fit in `u128`
+    let big = SmallValue::new(u128::MAX / 2);
+    assert!(big.mul_encoded(big).is_none());
+    let half = SmallValue::new(1u128 << 64);
+    let product = half.mul_encoded(SmallValue::new(1 << 62)).unwrap();
+    assert_eq!(product.min_bits(), 126);
+    assert_eq!(
+        product,
+        SmallValue::new(half.approximate() * SmallValue::new(1u128 << 62).approximate())
+    );
+    let big = SmallValue::new(1u128 << 65);
+    assert!(big.mul_encoded(big).is_none());
+}
+
+#[test]
+fn encoded_t_3() {
+    let small_value = SmallValue::new(-1000i64);
+    let approx = small_value.approximate();
+    assert_eq!(small_value.pow_encoded(0), Some(SmallValue::new(1)));
+    assert_eq!(
+        small_value.pow_encoded(2),
+        Some(SmallValue::new(approx * approx))
+    );
+    assert_eq!(
+        small_value.pow_encoded(5),
+        Some(SmallValue::new(approx.pow(5)))
+    );
+    assert_eq!(small_value.pow_encoded(7), None);
+    assert_eq!(
+        SmallValue::new(0u8).pow_encoded(u32::MAX),
+        Some(SmallValue::new(0))
+    );
+    assert_eq!(
+        ScaledValue::<u16, 255>::new(200).pow_encoded(2),
+        ScaledValue::<u16, 255>::new(200).checked_mul(ScaledValue::new(200))
+    );
+}
+
+#[test]
+fn encoded_t_4() {
+    let product = SmallValue::new(1u64 << 40)
+        .mul_encoded(SmallValue::new(1 << 20))
+        .unwrap();
+    assert_eq!(product.min_bits(), 60);
+    assert_eq!(SmallValue::new(100u8).div_encoded(SmallValue::new(0)), None);
+    assert_eq!(SmallValue::new(100u8).div_encoded(SmallValue::new(1)), None);
+}
+
+#[test]
+fn encoded_t_5() {
+    // every pair of `i8` and `u8` numbers, including products equal to `i8::MIN`
+    for a in i8::MIN..=i8::MAX {
+        for b in i8::MIN..=i8::MAX {
+            let (a, b) = (SmallValue::new(a), SmallValue::new(b));
+            assert_eq!(a.mul_encoded(b), a.checked_mul(b));
+            assert_eq!(a.div_encoded(b), a.checked_div(b));
+        }
+    }
+    for a in u8::MIN..=u8::MAX {
+        for b in u8::MIN..=u8::MAX {
+            let (a, b) = (SmallValue::new(a), SmallValue::new(b));
+            assert_eq!(a.mul_encoded(b), a.checked_mul(b));
+            assert_eq!(a.div_encoded(b), a.checked_div(b));
+        }
+    }
+
+    let product = SmallValue::new(-65i8).mul_encoded(SmallValue::new(3));
+    assert!(product.is_some());
+    assert_eq!(
+        product,
+        SmallValue::new(-65i8).checked_mul(SmallValue::new(3))
+    );
+}
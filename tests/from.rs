@@ -17,7 +17,7 @@ fn tuple_t_1() {
 #[test]
 fn tuple_t_2() {
     let tuple = (8, 50, false);
-    let small_value: SmallValue<u32> = tuple.into();
+    let small_value: SmallValue<u32> = tuple.try_into().unwrap();
     assert_eq!(small_value.min_bits(), 8);
 }
 
@@ -30,6 +30,6 @@ fn tuple_t_3() {
 
 #[test]
 fn tuple_t_5() {
-    let small_value: SmallValue<u128> = (128, 99).into();
+    let small_value: SmallValue<u128> = (128, 99).try_into().unwrap();
     assert_eq!(small_value.min_bits(), 128);
 }
@@ -0,0 +1,153 @@
+#![cfg(feature = "alloc")]
+
+use approx_int::{ApproxHistogram, ScaledValue, SmallValue};
+
+fn xorshift(seed: u64) -> impl FnMut() -> u64 {
+    let mut state = seed;
+    move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    }
+}
+
+// about logarithmically distributed numbers
+fn numbers(seed: u64, len: usize) -> Vec<u64> {
+    let mut rng = xorshift(seed);
+    (0..len).map(|_| rng() >> (rng() % 64)).collect()
+}
+
+#[test]
+fn histogram_t_0() {
+    let numbers = numbers(1, 10_000);
+    let histogram: ApproxHistogram<u64> = numbers.iter().copied().collect();
+    let mut sorted = numbers.clone();
+    sorted.sort_unstable();
+
+    assert_eq!(histogram.count(), 10_000);
+    assert_eq!(histogram.min(), sorted.first().copied());
+    assert_eq!(histogram.max(), sorted.last().copied());
+    for q in [0.0, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999, 1.0] {
+        let exact = sorted[((q * sorted.len() as f64) as usize).min(sorted.len() - 1)];
+        assert_eq!(
+            histogram.quantile(q),
+            Some(SmallValue::new(exact).approximate())
+        );
+    }
+    assert_eq!(histogram.quantile(-1.0), histogram.quantile(0.0));
+    assert_eq!(histogram.quantile(2.0), histogram.quantile(1.0));
+}
+
+#[test]
+fn histogram_t_1() {
+    let mut rng = xorshift(2);
+    let numbers: Vec<i32> = (0..5000).map(|_| (rng() as i32) >> (rng() % 32)).collect();
+    let mut histogram = ApproxHistogram::<i32>::new();
+    histogram.extend(numbers.iter().copied());
+    let mut sorted = numbers.clone();
+    sorted.sort_unstable();
+
+    for q in [0.0, 0.05, 0.5, 0.95, 1.0] {
+        let exact = sorted[((q * sorted.len() as f64) as usize).min(sorted.len() - 1)];
+        assert_eq!(
+            histogram.quantile(q),
+            Some(SmallValue::new(exact).approximate())
+        );
+    }
+
+    // the buckets are in ascending order and contain their numbers
+    let buckets: Vec<_> = histogram.iter().collect();
+    assert!(buckets.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert_eq!(buckets.iter().map(|(_, n)| n).sum::<u64>(), 5000);
+    for (value, n) in &buckets {
+        let range = value.bucket();
+        let inside = numbers
+            .iter()
+            .filter(|number| range.contains(number))
+            .count();
+        assert_eq!(inside as u64, *n);
+    }
+}
+
+#[test]
+fn histogram_t_2() {
+    let numbers = numbers(3, 10_000);
+    let histogram: ApproxHistogram<u64> = numbers.iter().copied().collect();
+    let exact = numbers.iter().map(|number| *number as f64).sum::<f64>() / numbers.len() as f64;
+    let mean = histogram.mean().unwrap();
+    let worst = SmallValue::<u64>::worst_case_relative_error_from(histogram.min().unwrap());
+    assert!(mean <= exact);
+    assert!((exact - mean) / exact * 100.0 <= worst);
+}
+
+#[test]
+fn histogram_t_3() {
+    let a = numbers(4, 3000);
+    let b = numbers(5, 2000);
+    let mut merged: ApproxHistogram<u64> = a.iter().copied().collect();
+    merged.merge(&b.iter().copied().collect());
+    let all: ApproxHistogram<u64> = a.iter().chain(&b).copied().collect();
+    assert_eq!(merged, all);
+
+    let mut histogram = ApproxHistogram::<u64>::new();
+    histogram.merge(&all);
+    assert_eq!(histogram, all);
+    histogram.merge(&ApproxHistogram::new());
+    assert_eq!(histogram, all);
+}
+
+#[test]
+fn histogram_t_4() {
+    let mut histogram = ApproxHistogram::<i8>::new();
+    assert!(histogram.is_empty());
+    assert_eq!(histogram.quantile(0.5), None);
+    assert_eq!(histogram.mean(), None);
+    assert_eq!(histogram.min(), None);
+
+    histogram.record_n(-1, 3);
+    histogram.record_n(0, 2);
+    histogram.record_n(100, 0);
+    // `-1` and `0` both approximate to zero, so they are in the same bucket
+    assert_eq!(histogram.iter().count(), 1);
+    assert_eq!(histogram.iter().next(), Some((SmallValue::new(0), 5)));
+    assert_eq!(histogram.count(), 5);
+    assert_eq!(histogram.quantile(1.0), Some(0));
+    assert_eq!(histogram.min(), Some(-1));
+    assert_eq!(histogram.max(), Some(0));
+
+    histogram.clear();
+    assert_eq!(histogram, ApproxHistogram::default());
+}
+
+#[test]
+fn histogram_t_5() {
+    let mut histogram = ApproxHistogram::<u32, 255>::new();
+    histogram.record(1_000_000);
+    let (value, n) = histogram.iter().next().unwrap();
+    assert_eq!(value, ScaledValue::<u32, 255>::new(1_000_000));
+    assert_eq!(n, 1);
+    assert!(value.bucket().contains(&1_000_000));
+}
+
+#[test]
+fn histogram_t_6() {
+    // the packed bits of negative values are not in numeric order
+    let numbers = [-70_000i32, 5, -3, 900, -1_000_000, 0, 42];
+    let histogram: ApproxHistogram<i32> = numbers.iter().copied().collect();
+    let buckets: Vec<_> = histogram.iter().map(|(value, _)| value).collect();
+    let mut expected: Vec<_> = numbers
+        .iter()
+        .map(|number| SmallValue::new(*number))
+        .collect();
+    expected.sort();
+    assert_eq!(buckets, expected);
+    assert_eq!(
+        histogram.quantile(0.0),
+        Some(SmallValue::new(-1_000_000).approximate())
+    );
+    assert_eq!(
+        histogram.quantile(1.0),
+        Some(SmallValue::new(900).approximate())
+    );
+}
@@ -0,0 +1,77 @@
+use approx_int::{ApproxInterval, SmallValue};
+
+#[test]
+fn interval_t_0() {
+    for value in [0u32, 1, 99, 1000, 123_456, u32::MAX] {
+        let interval = SmallValue::new(value).interval();
+        assert!(interval.contains(value));
+        assert_eq!(ApproxInterval::from(SmallValue::new(value)), interval);
+    }
+    assert_eq!(ApproxInterval::from(5i8).width(), 0);
+    assert_eq!(ApproxInterval::new(7, 3), ApproxInterval::new(3, 7));
+    assert_eq!(ApproxInterval::new(i8::MIN, i8::MAX).width(), 255);
+    assert_eq!(ApproxInterval::new(0, u128::MAX).width(), u128::MAX);
+}
+
+#[test]
+fn interval_t_1() {
+    let values = [-1500i64, -3, 0, 7, 250, 4096, 99_999];
+    for a in values {
+        for b in values {
+            let (x, y) = (SmallValue::new(a), SmallValue::new(b));
+            let (sum, interval) = x.add_with_bounds(y);
+            assert_eq!(sum, x + y);
+            assert!(interval.contains(a + b));
+            assert!(x.sub_with_bounds(y).1.contains(a - b));
+            assert!(x.mul_with_bounds(y).1.contains(a * b));
+            if !y.interval().contains(0) {
+                assert!(x.div_with_bounds(y).1.contains(a / b));
+            }
+        }
+    }
+}
+
+#[test]
+fn interval_t_2() {
+    // a chain of 20 operations keeps the exact result inside the interval
+    let numbers: Vec<u64> = (1..=20).map(|i| i * 7919 + i * i * 104_729).collect();
+    let mut exact = 0u64;
+    let mut interval = ApproxInterval::from(0u64);
+    for (i, number) in numbers.iter().enumerate() {
+        let small_value = SmallValue::new(*number);
+        if i % 5 == 4 {
+            exact *= 2;
+            interval = interval * ApproxInterval::from(2);
+        } else {
+            exact += number;
+            interval = interval + small_value.interval();
+        }
+    }
+    assert!(interval.contains(exact));
+    assert!(interval.width() > 0);
+    assert!(interval.width() < u128::from(exact / 10));
+}
+
+#[test]
+fn interval_t_3() {
+    let a = ApproxInterval::new(u8::MAX - 10, u8::MAX);
+    assert!(a.checked_add(a).is_none());
+    assert!(a.checked_mul(a).is_none());
+    assert!(ApproxInterval::new(0u8, 1).checked_sub(a).is_none());
+    assert!(a.checked_div(ApproxInterval::new(0, 3)).is_none());
+    assert_eq!(
+        ApproxInterval::new(-10i32, 20) * ApproxInterval::new(-3, 2),
+        ApproxInterval::new(-60, 40)
+    );
+    assert_eq!(
+        ApproxInterval::new(-10i32, 20) / ApproxInterval::new(-5, -2),
+        ApproxInterval::new(-10, 5)
+    );
+}
+
+#[should_panic(expected = "overflow")]
+#[test]
+fn interval_t_4() {
+    let a = ApproxInterval::from(SmallValue::new(u16::MAX));
+    let _ = a + a;
+}
@@ -0,0 +1,65 @@
+use approx_int::SmallValue;
+
+#[test]
+fn sum_t_0() {
+    let values: Vec<SmallValue<u64>> = (1..=5000u64).map(|i| SmallValue::new(i * 37)).collect();
+    let exact: u64 = values.iter().map(|value| value.approximate()).sum();
+    let sum: SmallValue<u64> = values.iter().sum();
+    assert_eq!(sum, SmallValue::new(exact));
+    assert_eq!(values.iter().copied().sum::<SmallValue<u64>>(), sum);
+    assert_eq!(SmallValue::checked_sum(&values), Some(sum));
+    assert_eq!(SmallValue::saturating_sum(values), sum);
+}
+
+#[test]
+fn sum_t_1() {
+    // folding with `+` re-encodes the running sum and drifts away
+    let values = vec![SmallValue::new(1000u64); 10_000];
+    let folded = values
+        .iter()
+        .fold(SmallValue::new(0), |sum, value| sum + *value);
+    let sum: SmallValue<u64> = values.iter().sum();
+    assert_eq!(sum, SmallValue::new(values[0].approximate() * 10_000));
+    assert!(folded < sum);
+}
+
+#[test]
+fn sum_t_2() {
+    let values = vec![SmallValue::new(u32::MAX / 3); 4];
+    assert_eq!(SmallValue::checked_sum(&values), None);
+    assert_eq!(
+        SmallValue::saturating_sum(&values),
+        SmallValue::new(u32::MAX)
+    );
+    let values = [SmallValue::new(i8::MIN), SmallValue::new(-100)];
+    assert_eq!(SmallValue::saturating_sum(values), SmallValue::new(i8::MIN));
+    assert_eq!(
+        SmallValue::<i32>::checked_sum(core::iter::empty::<SmallValue<i32>>()),
+        Some(SmallValue::new(0))
+    );
+}
+
+#[should_panic(expected = "overflow")]
+#[test]
+fn sum_t_3() {
+    let values = vec![SmallValue::new(u16::MAX); 2];
+    let _: SmallValue<u16> = values.into_iter().sum();
+}
+
+#[test]
+fn product_t_0() {
+    let values = [3u64, 1000, 77, 123_456].map(SmallValue::new);
+    let exact: u64 = values.iter().map(|value| value.approximate()).product();
+    assert_eq!(
+        values.iter().product::<SmallValue<u64>>(),
+        SmallValue::new(exact)
+    );
+    assert_eq!(
+        values.into_iter().product::<SmallValue<u64>>(),
+        SmallValue::new(exact)
+    );
+    assert_eq!(
+        core::iter::empty::<SmallValue<u8>>().product::<SmallValue<u8>>(),
+        SmallValue::new(1)
+    );
+}
@@ -0,0 +1,84 @@
+use approx_int::SmallValue;
+
+#[test]
+fn ilog_t_0() {
+    for value in 1..=u16::MAX {
+        let small_value = SmallValue::new(value);
+        let approx = small_value.approximate();
+        if approx == 0 {
+            assert_eq!(small_value.checked_ilog2(), None);
+            continue;
+        }
+        assert_eq!(small_value.ilog2(), approx.ilog2());
+        assert_eq!(small_value.ilog10(), approx.ilog10());
+        assert!(value.ilog2() - small_value.ilog2() <= 1);
+        assert!(value.ilog10() - small_value.ilog10() <= 1);
+        assert_eq!(u32::from(small_value.min_bits()) - 1, value.ilog2());
+    }
+}
+
+#[test]
+fn ilog_t_1() {
+    assert_eq!(SmallValue::new(0u32).checked_ilog2(), None);
+    assert_eq!(SmallValue::new(-1000i32).checked_ilog2(), None);
+    assert_eq!(SmallValue::new(-1000i32).checked_ilog10(), None);
+    assert_eq!(SmallValue::new(u128::MAX).ilog2(), 127);
+    assert_eq!(SmallValue::new(u128::MAX).ilog10(), u128::MAX.ilog10());
+}
+
+#[should_panic(expected = "must be positive")]
+#[test]
+fn ilog_t_2() {
+    SmallValue::new(0u64).ilog10();
+}
+
+#[test]
+fn isqrt_t_0() {
+    for value in [0u64, 1, 99, 1000, 1_000_000, u64::MAX] {
+        let small_value = SmallValue::new(value);
+        assert_eq!(
+            small_value.isqrt(),
+            SmallValue::new(small_value.approximate().isqrt())
+        );
+    }
+    assert_eq!(SmallValue::new(1_000_000u64).isqrt().approximate(), 990);
+    assert_eq!(SmallValue::new(-1i8).isqrt(), SmallValue::new(0));
+}
+
+#[should_panic(expected = "cannot be negative")]
+#[test]
+fn isqrt_t_1() {
+    SmallValue::new(-1000i32).isqrt();
+}
+
+#[test]
+fn pow_t_0() {
+    let small_value = SmallValue::new(-30i32);
+    let approx = small_value.approximate();
+    assert_eq!(small_value.pow(3), SmallValue::new(approx.pow(3)));
+    assert_eq!(small_value.checked_pow(3), Some(small_value.pow(3)));
+    assert_eq!(small_value.checked_pow(10), None);
+    assert_eq!(SmallValue::new(2u8).pow(0), SmallValue::new(1));
+}
+
+#[should_panic(expected = "overflow")]
+#[test]
+fn pow_t_1() {
+    SmallValue::new(1000u16).pow(2);
+}
+
+#[test]
+fn sign_t_0() {
+    for value in -1000..1000i32 {
+        let small_value = SmallValue::new(value);
+        let approx = small_value.approximate();
+        assert_eq!(small_value.signum(), approx.signum());
+        assert_eq!(small_value.is_negative(), approx.is_negative());
+        assert_eq!(small_value.is_positive(), approx.is_positive());
+        assert_eq!(small_value.abs().approximate(), approx.abs());
+        assert!(small_value.abs().is_canonical());
+    }
+    assert_eq!(SmallValue::new(i64::MIN).abs(), SmallValue::new(i64::MAX));
+    assert_eq!(SmallValue::new(7u8).signum(), 1);
+    assert_eq!(SmallValue::new(0u8).signum(), 0);
+}
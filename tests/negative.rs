@@ -0,0 +1,81 @@
+use approx_int::{ScaledValue, SmallValue, SmallValueError};
+
+#[test]
+fn negative_t_0() {
+    for value in i16::MIN + 1..0 {
+        let negative = SmallValue::new(value);
+        let positive = SmallValue::new(-value);
+        assert_eq!(negative.approximate(), -positive.approximate());
+        assert_eq!(
+            (negative.min_bits(), negative.percent()),
+            (positive.min_bits(), positive.percent())
+        );
+        assert_eq!(negative.flag(), negative.approximate() != 0);
+    }
+}
+
+#[test]
+fn negative_t_1() {
+    for value in i8::MIN + 1..=i8::MAX {
+        assert_eq!(
+            ScaledValue::<i8, 255>::new(value).approximate(),
+            -ScaledValue::<i8, 255>::new(-value).approximate()
+        );
+    }
+}
+
+#[test]
+fn negative_t_2() {
+    // `T::MIN` is encoded like `-T::MAX`
+    assert_eq!(SmallValue::new(i32::MIN), SmallValue::new(-i32::MAX));
+    assert_eq!(SmallValue::new(i128::MIN), SmallValue::new(-i128::MAX));
+    let tuple: (u8, u8, bool) = SmallValue::new(i64::MIN).into();
+    assert_eq!(tuple, (63, 99, true));
+    assert!(SmallValue::new(i64::MIN).approximate() < 0);
+}
+
+#[test]
+fn negative_t_3() {
+    assert_eq!(SmallValue::new(-1i32).approximate(), 0);
+    assert_eq!(SmallValue::new(-1000i32).approximate(), -990);
+    assert!(SmallValue::new(-1000i32).approximate() > -1000);
+    let (min, max) = SmallValue::new(-1000i32).bounds();
+    assert!(min <= -1000 && -1000 <= max);
+}
+
+#[test]
+fn negative_t_4() {
+    for tuple in [(10, 50), (64, 50), (128, 99)] {
+        let small_value = SmallValue::<u128>::from_legacy((tuple.0, tuple.1, false)).unwrap();
+        assert_eq!((small_value.min_bits(), small_value.percent()), tuple);
+    }
+}
+
+#[test]
+fn negative_t_5() {
+    // `new(-1000)`, `new(-5)`, `new(-1)` and `new(i32::MIN + 1)` before `2.0`
+    let small_value = SmallValue::<i32>::from_legacy((10, 100, true)).unwrap();
+    assert_eq!(small_value.approximate(), -1000);
+    let small_value = SmallValue::<i32>::from_legacy((3, 91, true)).unwrap();
+    assert_eq!(small_value.approximate(), -6);
+    let small_value = SmallValue::<i32>::from_legacy((0, 199, true)).unwrap();
+    assert_eq!(small_value.approximate(), -1);
+    let small_value = SmallValue::<i32>::from_legacy((31, 100, true)).unwrap();
+    assert_eq!(small_value.approximate(), -2126008764);
+}
+
+#[test]
+fn negative_t_6() {
+    assert_eq!(
+        SmallValue::<u32>::from_legacy((10, 100, true)),
+        Err(SmallValueError::NegativeUnsigned)
+    );
+    assert!(matches!(
+        SmallValue::<i32>::from_legacy((32, 50, true)),
+        Err(SmallValueError::MinBitsOutOfRange { min_bits: 32, .. })
+    ));
+    assert!(matches!(
+        SmallValue::<i32>::from_legacy((10, 200, true)),
+        Err(SmallValueError::PercentOutOfRange { percent: 200, .. })
+    ));
+}
@@ -1,5 +1,3 @@
-use std::u32;
-
 use approx_int::SmallValue;
 
 #[test]
@@ -95,3 +93,168 @@ fn rem_t_2() {
         .checked_rem(SmallValue::new(u32::MIN))
         .is_none());
 }
+
+#[test]
+fn add_t_4() {
+    // the plain number is not encoded before the addition
+    let small_value = SmallValue::new(1000u64);
+    assert_eq!(
+        small_value + 1,
+        SmallValue::new(small_value.approximate() + 1)
+    );
+    assert_eq!(1 + small_value, small_value + 1);
+}
+
+#[test]
+fn sub_t_4() {
+    let small_value = SmallValue::new(1000i64);
+    assert_eq!(
+        small_value - 5,
+        SmallValue::new(small_value.approximate() - 5)
+    );
+    assert_eq!(
+        5 - small_value,
+        SmallValue::new(5 - small_value.approximate())
+    );
+}
+
+#[test]
+fn mul_div_rem_t_0() {
+    let small_value = SmallValue::new(1000u32);
+    let approx = small_value.approximate();
+    assert_eq!(small_value * 3, SmallValue::new(approx * 3));
+    assert_eq!(small_value / 3, SmallValue::new(approx / 3));
+    assert_eq!(small_value % 7, SmallValue::new(approx % 7));
+    assert_eq!(3_000_000 / small_value, SmallValue::new(3_000_000 / approx));
+    assert_eq!(7777 % small_value, SmallValue::new(7777 % approx));
+    assert_eq!(3 * small_value, small_value * 3);
+}
+
+#[test]
+fn assign_t_0() {
+    let small_value = SmallValue::new(1000u64);
+    let mut assigned = small_value;
+    assigned += 24;
+    assert_eq!(assigned, small_value + 24);
+    assigned -= SmallValue::new(100);
+    assert_eq!(assigned, small_value + 24 - SmallValue::new(100));
+    assigned *= 3;
+    assigned /= SmallValue::new(3);
+    assigned %= 1000;
+    assert_eq!(
+        assigned,
+        (small_value + 24 - SmallValue::new(100)) * 3 / SmallValue::new(3) % 1000
+    );
+}
+
+#[should_panic(expected = "overflow")]
+#[test]
+fn assign_t_1() {
+    let mut small_value = SmallValue::new(u8::MAX);
+    small_value += u8::MAX;
+}
+
+#[test]
+fn neg_t_0() {
+    for value in -1000..1000i32 {
+        let small_value = SmallValue::new(value);
+        assert_eq!((-small_value).approximate(), -small_value.approximate());
+        assert_eq!(-(-small_value), small_value);
+    }
+    assert_eq!(-SmallValue::new(i64::MIN), SmallValue::new(i64::MAX));
+    assert!((-SmallValue::new(12345i32)).is_canonical());
+
+    let zero = SmallValue::new(0i32);
+    assert_eq!(-zero, zero);
+    assert!((-zero).is_canonical());
+    assert!(!(-zero).flag());
+}
+
+#[test]
+fn saturating_t_0() {
+    let max = SmallValue::new(u32::MAX);
+    // the approximation of `u32::MAX` is below `u32::MAX`, the counter stays pinned
+    assert!(max.approximate() < u32::MAX);
+    assert_eq!(max.saturating_add(max), max);
+    assert_eq!(max.saturating_add(SmallValue::new(1)), max);
+    assert_eq!(max.saturating_mul(SmallValue::new(1000)), max);
+    assert_eq!(
+        SmallValue::new(5u32).saturating_sub(SmallValue::new(1000)),
+        SmallValue::new(0)
+    );
+}
+
+#[test]
+fn saturating_t_1() {
+    let min = SmallValue::new(i64::MIN);
+    let max = SmallValue::new(i64::MAX);
+    assert_eq!(min.saturating_add(min), min);
+    assert_eq!(min.saturating_sub(max), min);
+    assert_eq!(max.saturating_sub(min), max);
+    assert_eq!(min.saturating_mul(max), min);
+    let divisor = SmallValue::new(-1000);
+    assert_eq!(
+        min.saturating_div(divisor),
+        SmallValue::new(min.approximate() / divisor.approximate())
+    );
+}
+
+#[test]
+fn saturating_t_2() {
+    let mut counter = SmallValue::new(0u16);
+    for _ in 0..100 {
+        counter = counter.saturating_add(SmallValue::new(10_000));
+    }
+    assert_eq!(counter, SmallValue::new(u16::MAX));
+}
+
+#[test]
+fn wrapping_t_0() {
+    let max = SmallValue::new(u8::MAX);
+    let approx = max.approximate();
+    assert_eq!(
+        max.wrapping_add(max),
+        SmallValue::new(approx.wrapping_add(approx))
+    );
+    assert_eq!(
+        SmallValue::new(0u8).wrapping_sub(max),
+        SmallValue::new(0u8.wrapping_sub(approx))
+    );
+    assert_eq!(
+        max.wrapping_mul(max),
+        SmallValue::new(approx.wrapping_mul(approx))
+    );
+    assert_eq!(
+        max.wrapping_div(SmallValue::new(10)),
+        max / SmallValue::new(10)
+    );
+    assert_eq!(
+        max.wrapping_rem(SmallValue::new(10)),
+        max % SmallValue::new(10)
+    );
+}
+
+#[test]
+fn overflowing_t_0() {
+    let max = SmallValue::new(i32::MAX);
+    let approx = max.approximate();
+    let (value, overflow) = max.overflowing_add(max);
+    assert!(overflow);
+    assert_eq!(value, SmallValue::new(approx.wrapping_add(approx)));
+    assert_eq!(max.overflowing_sub(max), (SmallValue::new(0), false));
+    assert!(max.overflowing_mul(SmallValue::new(1000)).1);
+    assert_eq!(
+        max.overflowing_div(SmallValue::new(1000)),
+        (max / SmallValue::new(1000), false)
+    );
+    assert_eq!(
+        max.overflowing_rem(SmallValue::new(1000)),
+        (max % SmallValue::new(1000), false)
+    );
+}
+
+#[should_panic(expected = "divide by zero")]
+#[test]
+fn overflowing_t_1() {
+    let _ = SmallValue::new(100u32).overflowing_div(SmallValue::new(0));
+}
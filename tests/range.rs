@@ -67,6 +67,7 @@ fn bound_t_7() {
 }
 
 #[test]
+#[allow(clippy::identity_op)]
 fn bound_t_8() {
     let value = u64::MIN + 10_000;
     let small_value: SmallValue<u64> = value.into();
@@ -0,0 +1,146 @@
+use approx_int::{ScaledValue, SmallValue, SpecialBytes};
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+// all valid encodings, `try_from` returns canonical values
+fn all_values<T: SpecialBytes + Debug, const SCALE: u8>() -> Vec<ScaledValue<T, SCALE>> {
+    let mut values = BTreeSet::new();
+    for min_bits in 0..=T::bits() {
+        for percent in 0..=SCALE {
+            for flag in [false, true] {
+                if let Ok(value) = ScaledValue::try_from((min_bits, percent, flag)) {
+                    values.insert(value);
+                }
+            }
+        }
+    }
+    values.into_iter().collect()
+}
+
+fn check<T: SpecialBytes + Debug, const SCALE: u8>() {
+    let expected = all_values::<T, SCALE>();
+    let values: Vec<_> = ScaledValue::<T, SCALE>::iter_all().collect();
+    assert_eq!(values, expected);
+    assert_eq!(ScaledValue::<T, SCALE>::count_representable(), values.len());
+
+    for pair in values.windows(2) {
+        assert!(pair[0].is_canonical());
+        assert_eq!(pair[0].next_up(), Some(pair[1]));
+        assert_eq!(pair[1].next_down(), Some(pair[0]));
+    }
+    assert_eq!(values.first().unwrap().next_down(), None);
+    assert_eq!(values.last().unwrap().next_up(), None);
+}
+
+#[test]
+fn representable_t_0() {
+    check::<u8, 100>();
+    check::<i8, 100>();
+    check::<u16, 100>();
+    check::<i16, 100>();
+    check::<u32, 255>();
+    check::<i32, 2>();
+}
+
+#[test]
+fn representable_t_1() {
+    let values: Vec<_> = SmallValue::<i64>::iter_all().collect();
+    assert_eq!(values.len(), SmallValue::<i64>::count_representable());
+    assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(values
+        .windows(2)
+        .all(|pair| pair[0].approximate() <= pair[1].approximate()));
+    // `new(i64::MIN)` is the smallest value of the type
+    assert_eq!(values.first(), Some(&SmallValue::new(i64::MIN)));
+    assert_eq!(values.last(), Some(&SmallValue::new(i64::MAX)));
+}
+
+#[test]
+fn representable_t_2() {
+    assert_eq!(
+        SmallValue::<u128>::count_representable(),
+        SmallValue::<u128>::iter_all().count()
+    );
+    assert_eq!(
+        SmallValue::<i128>::iter_all().last(),
+        Some(SmallValue::new(i128::MAX))
+    );
+
+    // there is a single zero between `-1` and `1`
+    let zero = SmallValue::new(0i32);
+    let minus_one = zero.next_down().unwrap();
+    assert_eq!(minus_one.approximate(), -1);
+    assert_eq!(minus_one.next_up(), Some(zero));
+    assert_eq!(zero.next_up().unwrap().approximate(), 1);
+    assert_eq!(SmallValue::new(0u32).next_down(), None);
+}
+
+#[test]
+fn representable_t_3() {
+    // the non-empty buckets of the values in ascending order cover the whole type without gaps
+    let mut next_start = Some(i16::MIN);
+    for value in SmallValue::<i16>::iter_all() {
+        let bucket = value.bucket();
+        if !bucket.is_empty() {
+            assert_eq!(Some(*bucket.start()), next_start);
+            next_start = bucket.end().checked_add(1);
+        }
+    }
+    assert_eq!(next_start, None);
+}
+
+fn check_reachable<T: SpecialBytes + Debug, const SCALE: u8>() {
+    let values: Vec<_> = ScaledValue::<T, SCALE>::iter_all().collect();
+    let reachable: Vec<_> = values
+        .iter()
+        .copied()
+        .filter(|value| !value.bucket().is_empty())
+        .collect();
+    for value in &values {
+        assert_eq!(value.is_reachable(), !value.bucket().is_empty());
+    }
+    assert_eq!(
+        ScaledValue::<T, SCALE>::iter_reachable().collect::<Vec<_>>(),
+        reachable
+    );
+    assert_eq!(ScaledValue::<T, SCALE>::count_reachable(), reachable.len());
+
+    for pair in reachable.windows(2) {
+        assert_eq!(pair[0].next_up_reachable(), Some(pair[1]));
+        assert_eq!(pair[1].next_down_reachable(), Some(pair[0]));
+    }
+    assert_eq!(reachable.first().unwrap().next_down_reachable(), None);
+    assert_eq!(reachable.last().unwrap().next_up_reachable(), None);
+}
+
+#[test]
+fn representable_t_4() {
+    check_reachable::<u8, 100>();
+    check_reachable::<i8, 100>();
+    check_reachable::<u16, 100>();
+    check_reachable::<i16, 100>();
+    check_reachable::<u16, 255>();
+    check_reachable::<i16, 7>();
+    check_reachable::<u32, 2>();
+    check_reachable::<i32, 3>();
+    assert_eq!(SmallValue::<u16>::count_reachable(), 533);
+    assert_eq!(SmallValue::<u16>::count_representable(), 760);
+}
+
+#[test]
+fn representable_t_5() {
+    // every result of `new` is reachable, `next_up` can leave the reachable values
+    let value = SmallValue::new(1000u16);
+    assert!(value.is_reachable());
+    let gap = SmallValue::<u16>::iter_all()
+        .find(|value| !value.is_reachable())
+        .unwrap();
+    let before = gap.next_down().unwrap();
+    assert_eq!(before.next_up(), Some(gap));
+    assert!(before.next_up_reachable() > Some(gap));
+    for number in (0..=u64::MAX).step_by(1 << 50) {
+        assert!(SmallValue::new(number).is_reachable());
+    }
+    assert!(SmallValue::<u128>::count_reachable() < SmallValue::<u128>::count_representable());
+    assert!(!SmallValue::<u32>::from_raw_unchecked(5, 200, false).is_reachable());
+}
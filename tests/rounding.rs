@@ -0,0 +1,145 @@
+use approx_int::{Rounding, ScaledValue, SmallValue};
+
+#[test]
+fn rounding_t_0() {
+    // numbers above the largest representable number saturate
+    let largest = SmallValue::new_with(u16::MAX, Rounding::Ceil).approximate();
+    for value in u16::MIN..=u16::MAX {
+        let floor = SmallValue::new_with(value, Rounding::Floor);
+        let ceil = SmallValue::new_with(value, Rounding::Ceil);
+        assert!(floor.is_valid() && ceil.is_valid());
+        assert!(floor.approximate() <= value);
+        assert!(ceil.approximate() >= value || ceil.approximate() == largest);
+    }
+}
+
+#[test]
+fn rounding_t_1() {
+    let largest = SmallValue::new_with(i16::MAX, Rounding::Ceil).approximate();
+    let smallest = SmallValue::new_with(i16::MIN, Rounding::Floor).approximate();
+    for value in i16::MIN..=i16::MAX {
+        let floor = SmallValue::new_with(value, Rounding::Floor);
+        let ceil = SmallValue::new_with(value, Rounding::Ceil);
+        let toward_zero = SmallValue::new_with(value, Rounding::TowardZero);
+        assert!(floor.is_valid() && ceil.is_valid() && toward_zero.is_valid());
+        assert!(floor.approximate() <= value || floor.approximate() == smallest);
+        assert!(ceil.approximate() >= value || ceil.approximate() == largest);
+        assert!(toward_zero.approximate().unsigned_abs() <= value.unsigned_abs());
+        assert!(
+            toward_zero.approximate() == 0 || toward_zero.approximate().signum() == value.signum()
+        );
+    }
+}
+
+#[test]
+fn rounding_t_2() {
+    for value in i16::MIN..=i16::MAX {
+        let nearest = SmallValue::new_with(value, Rounding::Nearest).approximate();
+        let floor = SmallValue::new_with(value, Rounding::Floor).approximate();
+        let ceil = SmallValue::new_with(value, Rounding::Ceil).approximate();
+        let distance = |approx: i16| (i32::from(approx) - i32::from(value)).abs();
+        assert!(distance(nearest) <= distance(floor));
+        assert!(distance(nearest) <= distance(ceil));
+    }
+}
+
+#[test]
+fn rounding_t_3() {
+    let mut value = u64::MAX / 3;
+    while value > 0 {
+        assert!(SmallValue::new_with(value, Rounding::Floor).approximate() <= value);
+        assert!(SmallValue::new_with(value, Rounding::Ceil).approximate() >= value);
+        assert!(ScaledValue::<u64, 255>::new_with(value, Rounding::Ceil).approximate() >= value);
+        let nearest = SmallValue::new_with(value, Rounding::Nearest).approximate();
+        assert!(nearest.abs_diff(value) <= SmallValue::new(value).approximate().abs_diff(value));
+        value = value / 7 * 5;
+    }
+}
+
+#[test]
+fn rounding_t_4() {
+    let mut value = i64::MIN / 3;
+    while value < 0 {
+        assert!(SmallValue::new_with(value, Rounding::Floor).approximate() <= value);
+        assert!(SmallValue::new_with(value, Rounding::Ceil).approximate() >= value);
+        assert!(SmallValue::new_with(value, Rounding::TowardZero).approximate() >= value);
+        value = value / 7 * 5;
+    }
+}
+
+#[test]
+fn rounding_t_5() {
+    let ceil = SmallValue::new_with(63u32, Rounding::Ceil);
+    assert_eq!(ceil.approximate(), 63);
+    assert_eq!(ceil.min_bits(), 7);
+    assert_eq!(SmallValue::new_with(0u32, Rounding::Ceil).approximate(), 0);
+    assert_eq!(SmallValue::new_with(0i32, Rounding::Floor).approximate(), 0);
+}
+
+#[test]
+fn rounding_t_6() {
+    let ceil = SmallValue::new_with(u128::MAX, Rounding::Ceil);
+    assert_eq!(ceil.min_bits(), 128);
+    assert_eq!(
+        ceil.approximate(),
+        SmallValue::new_with(u128::MAX, Rounding::Floor).approximate()
+    );
+    let floor = SmallValue::new_with(i128::MIN, Rounding::Floor);
+    assert!(floor.is_valid());
+    assert!(floor.approximate() < 0);
+}
+
+fn brute_force<T: Copy + Ord + Into<i32>>(table: &[T], value: T, rounding: Rounding) -> T {
+    // the table is sorted, the closest values in both directions saturate at the ends
+    let above = table.partition_point(|approx| *approx < value);
+    let below = table.partition_point(|approx| *approx <= value);
+    let floor = table[below.max(1) - 1];
+    let ceil = table[above.min(table.len() - 1)];
+    let distance = |approx: T| (approx.into() - value.into()).abs();
+    match rounding {
+        Rounding::Floor => floor,
+        Rounding::Ceil => ceil,
+        Rounding::TowardZero if value.into() < 0 => ceil,
+        Rounding::TowardZero => floor,
+        Rounding::Nearest => [floor, ceil]
+            .into_iter()
+            .min_by_key(|approx| (distance(*approx), Into::<i32>::into(*approx).abs()))
+            .unwrap(),
+    }
+}
+
+#[test]
+fn rounding_t_7() {
+    let modes = [
+        Rounding::Floor,
+        Rounding::Ceil,
+        Rounding::Nearest,
+        Rounding::TowardZero,
+    ];
+    let table: Vec<i16> = SmallValue::<i16>::iter_all()
+        .map(|value| value.approximate())
+        .collect();
+    for value in i16::MIN..=i16::MAX {
+        for rounding in modes {
+            let approx = SmallValue::new_with(value, rounding).approximate();
+            assert_eq!(approx, brute_force(&table, value, rounding));
+        }
+    }
+
+    let table: Vec<u16> = SmallValue::<u16>::iter_all()
+        .map(|value| value.approximate())
+        .collect();
+    for value in u16::MIN..=u16::MAX {
+        for rounding in modes {
+            let approx = SmallValue::new_with(value, rounding).approximate();
+            assert_eq!(approx, brute_force(&table, value, rounding));
+        }
+    }
+}
+
+#[test]
+fn rounding_t_8() {
+    let nearest = SmallValue::new_with(8191u32, Rounding::Nearest);
+    assert_eq!(nearest.approximate(), 8175);
+    assert_eq!(SmallValue::try_from((15, 25)), Ok(nearest));
+}
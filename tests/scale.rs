@@ -0,0 +1,85 @@
+use approx_int::{ScaledValue, SmallValue, SmallValueError};
+
+fn error_rate(original: u64, approximate: u64) -> f64 {
+    (original - approximate) as f64 / original as f64 * 100.0
+}
+
+#[test]
+fn scale_t_0() {
+    let (mut worst_percent, mut worst_fine) = (0.0f64, 0.0f64);
+    let mut value = u64::MAX;
+    while value > 100_000 {
+        let percent = SmallValue::new(value).approximate();
+        let fine = ScaledValue::<u64, 255>::new(value).approximate();
+        worst_percent = worst_percent.max(error_rate(value, percent));
+        worst_fine = worst_fine.max(error_rate(value, fine));
+        value = value / 3 * 2;
+    }
+    assert!(worst_fine < 0.8);
+    assert!(worst_fine < worst_percent);
+}
+
+#[test]
+fn scale_t_1() {
+    assert_eq!(ScaledValue::<u32, 255>::new(u32::MAX).percent(), 254);
+    assert_eq!(ScaledValue::<u32, 128>::new(u32::MAX).percent(), 127);
+    assert_eq!(ScaledValue::<u32, 2>::new(u32::MAX).percent(), 1);
+}
+
+#[test]
+fn scale_t_2() {
+    for value in 2..=i8::MAX {
+        let approx = ScaledValue::<i8, 200>::new(value).approximate();
+        assert!(0 < approx && approx < value);
+    }
+}
+
+#[test]
+fn scale_t_3() {
+    assert!(ScaledValue::<u32, 255>::try_from((8, 200, false)).is_ok());
+    assert_eq!(
+        SmallValue::<u32>::try_from((8, 200, false)),
+        Err(SmallValueError::PercentOutOfRange {
+            percent: 200,
+            min: 1,
+            max: 99
+        })
+    );
+}
+
+#[test]
+fn scale_t_4() {
+    for value in [10_000u64, 1 << 40, u64::MAX - 10_000] {
+        let (min, max) = ScaledValue::<u64, 128>::new(value).bounds();
+        assert!(min < value && value < max);
+    }
+}
+
+#[test]
+fn scale_t_5() {
+    for value in -100_000i64..100_000 {
+        let small_value = ScaledValue::<i64, 255>::new(value);
+        assert!(small_value.is_valid());
+        assert_eq!(
+            ScaledValue::from_bits(small_value.to_bits()),
+            Ok(small_value)
+        );
+    }
+}
+
+#[test]
+fn scale_t_6() {
+    let a = ScaledValue::<u64, 200>::new(1 << 40);
+    let b = ScaledValue::<u64, 200>::new(1 << 20);
+    assert_eq!(
+        (a + b).approximate(),
+        ScaledValue::<u64, 200>::new(a.approximate() + b.approximate()).approximate()
+    );
+    assert!(a.checked_mul(a).is_none());
+}
+
+#[test]
+fn scale_t_7() {
+    let value: ScaledValue<u64, 255> = 8838183818381831838u64.into();
+    assert_eq!(value, ScaledValue::new(8838183818381831838));
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "serde")]
+
+use approx_int::{DynSmallValue, IntKind, SmallValue};
+
+#[test]
+fn serde_t_0() {
+    let small_value = SmallValue::new(128u64);
+    let json = serde_json::to_string(&small_value).unwrap();
+    assert_eq!(
+        json,
+        r#"{"min_bits":8,"percent":63,"flag":false,"approximate":126}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<SmallValue<u64>>(&json).unwrap(),
+        small_value
+    );
+}
+
+#[test]
+fn serde_t_1() {
+    let small_value = SmallValue::new(-3200311i64);
+    let json = serde_json::to_string(&small_value).unwrap();
+    assert_eq!(
+        serde_json::from_str::<SmallValue<i64>>(&json).unwrap(),
+        small_value
+    );
+}
+
+#[test]
+fn serde_t_2() {
+    let small_value: SmallValue<u32> =
+        serde_json::from_str(r#"{"min_bits":8,"percent":50}"#).unwrap();
+    assert_eq!(small_value, SmallValue::try_from((8, 50, false)).unwrap());
+}
+
+#[test]
+fn serde_t_3() {
+    assert!(serde_json::from_str::<SmallValue<u32>>(r#"{"min_bits":33,"percent":50}"#).is_err());
+    assert!(
+        serde_json::from_str::<SmallValue<u32>>(r#"{"min_bits":8,"percent":50,"flag":true}"#)
+            .is_err()
+    );
+    assert!(serde_json::from_str::<SmallValue<u32>>(r#"{"min_bits":8}"#).is_err());
+    assert!(
+        serde_json::from_str::<SmallValue<u32>>(r#"{"min_bits":8,"percent":50,"sign":true}"#)
+            .is_err()
+    );
+}
+
+#[test]
+fn serde_t_4() {
+    let small_value = SmallValue::new(u128::MAX);
+    let bytes = bincode::serialize(&small_value).unwrap();
+    assert_eq!(bytes, small_value.to_le_bytes());
+    assert_eq!(
+        bincode::deserialize::<SmallValue<u128>>(&bytes).unwrap(),
+        small_value
+    );
+}
+
+#[test]
+fn serde_t_5() {
+    let bytes = bincode::serialize(&SmallValue::new(-5i32)).unwrap();
+    assert!(bincode::deserialize::<SmallValue<u32>>(&bytes).is_err());
+}
+
+#[test]
+fn serde_t_6() {
+    let value = DynSmallValue::from(SmallValue::new(-5000i64));
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(
+        json,
+        format!(
+            r#"{{"kind":"i64","min_bits":{},"percent":{},"flag":true}}"#,
+            value.min_bits(),
+            value.percent()
+        )
+    );
+    assert_eq!(serde_json::from_str::<DynSmallValue>(&json).unwrap(), value);
+
+    let bytes = bincode::serialize(&value).unwrap();
+    assert_eq!(bytes.len(), 3);
+    assert_eq!(
+        bincode::deserialize::<DynSmallValue>(&bytes).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn serde_t_7() {
+    assert!(serde_json::from_str::<DynSmallValue>(
+        r#"{"kind":"u32","min_bits":8,"percent":50,"flag":true}"#
+    )
+    .is_err());
+    assert!(
+        serde_json::from_str::<DynSmallValue>(r#"{"kind":"u7","min_bits":8,"percent":50}"#)
+            .is_err()
+    );
+    assert!(serde_json::from_str::<DynSmallValue>(r#"{"min_bits":8,"percent":50}"#).is_err());
+    assert_eq!(
+        serde_json::from_str::<DynSmallValue>(r#"{"kind":"u32","min_bits":8,"percent":50}"#)
+            .unwrap()
+            .kind(),
+        IntKind::U32
+    );
+
+    assert!(bincode::deserialize::<DynSmallValue>(&[10, 0, 0]).is_err());
+}
+
+#[test]
+fn serde_t_8() {
+    // the type name is not borrowed from the input
+    let value = DynSmallValue::from(SmallValue::new(-5000i64));
+    let json = serde_json::to_vec(&value).unwrap();
+    assert_eq!(
+        serde_json::from_reader::<_, DynSmallValue>(json.as_slice()).unwrap(),
+        value
+    );
+    let tree = serde_json::to_value(value).unwrap();
+    assert_eq!(tree["kind"], "i64");
+    assert_eq!(
+        serde_json::from_value::<DynSmallValue>(tree).unwrap(),
+        value
+    );
+    assert_eq!(
+        serde_json::from_str::<DynSmallValue>(
+            r#"{"kind":"\u0069\u0031\u0036","min_bits":8,"percent":50}"#
+        )
+        .unwrap()
+        .kind(),
+        IntKind::I16
+    );
+    assert_eq!(serde_json::to_string(&IntKind::U128).unwrap(), r#""u128""#);
+    assert_eq!(
+        serde_json::from_reader::<_, IntKind>(&br#""u8""#[..]).unwrap(),
+        IntKind::U8
+    );
+}
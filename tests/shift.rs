@@ -0,0 +1,73 @@
+use approx_int::{Rounding, SmallValue};
+
+#[test]
+fn shift_t_0() {
+    // large numbers keep the percentage
+    let small_value = SmallValue::new(123_456_789u64);
+    let shifted = small_value << 10;
+    assert_eq!(shifted.min_bits(), small_value.min_bits() + 10);
+    assert_eq!(shifted.percent(), small_value.percent());
+    assert_eq!(shifted >> 10, small_value);
+}
+
+#[test]
+fn shift_t_1() {
+    for value in (0..u32::from(u16::MAX)).step_by(7) {
+        let small_value = SmallValue::new(value);
+        let approx = small_value.approximate();
+        for k in [1, 5, 10] {
+            assert_eq!(
+                small_value << k,
+                SmallValue::new_with(approx << k, Rounding::Nearest)
+            );
+            assert_eq!(
+                small_value >> k,
+                SmallValue::new_with(approx >> k, Rounding::Nearest)
+            );
+        }
+    }
+}
+
+#[test]
+fn shift_t_2() {
+    for value in -5000..0i32 {
+        let small_value = SmallValue::new(value);
+        let approx = small_value.approximate();
+        assert_eq!(
+            small_value << 3,
+            SmallValue::new_with(approx << 3, Rounding::Nearest)
+        );
+        assert_eq!(
+            small_value >> 3,
+            SmallValue::new_with(approx >> 3, Rounding::Nearest)
+        );
+    }
+}
+
+#[test]
+fn shift_t_3() {
+    assert_eq!(SmallValue::new(0u64) << 63, SmallValue::new(0));
+    assert!(SmallValue::new(1u64 << 40).checked_shl(30).is_none());
+    assert!(SmallValue::new(1u64 << 40).checked_shl(20).is_some());
+    assert!(SmallValue::new(5i8).checked_shl(8).is_none());
+    assert!(SmallValue::new(5i8).checked_shr(8).is_none());
+    assert_eq!(
+        SmallValue::new(1000u16).checked_shr(15),
+        Some(SmallValue::new(0))
+    );
+}
+
+#[test]
+fn shift_t_4() {
+    let mut small_value = SmallValue::new(3000u32);
+    small_value <<= 4;
+    assert_eq!(small_value, SmallValue::new(3000u32) << 4);
+    small_value >>= 4;
+    assert_eq!(small_value, SmallValue::new(3000u32) << 4 >> 4);
+}
+
+#[should_panic(expected = "shift left with overflow")]
+#[test]
+fn shift_t_5() {
+    let _ = SmallValue::new(u32::MAX) << 1;
+}
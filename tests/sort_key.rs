@@ -0,0 +1,112 @@
+use approx_int::{ScaledValue, SmallValue, SmallValueError};
+use std::collections::BTreeSet;
+
+#[test]
+fn sort_key_t_0() {
+    let values: BTreeSet<SmallValue<i16>> = (i16::MIN..=i16::MAX).map(SmallValue::new).collect();
+    let keys: Vec<[u8; 2]> = values.iter().map(|value| value.to_sort_key()).collect();
+    // the values are in the order of `Ord`, the keys must be strictly ascending
+    assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+
+    for (value, key) in values.iter().zip(&keys) {
+        assert_eq!(SmallValue::<i16>::from_sort_key(*key), Ok(*value));
+    }
+}
+
+#[test]
+fn sort_key_t_1() {
+    let values: BTreeSet<SmallValue<u16>> = (0..=u16::MAX).map(SmallValue::new).collect();
+    let mut previous = None;
+    for value in values {
+        let key = value.to_sort_key();
+        assert_eq!(key, value.approximate().to_be_bytes());
+        assert!(previous < Some(key));
+        assert_eq!(SmallValue::<u16>::from_sort_key(key), Ok(value));
+        previous = Some(key);
+    }
+}
+
+#[test]
+fn sort_key_t_2() {
+    // small negative numbers approximate to zero and get its key
+    let zero = SmallValue::new(0i32);
+    assert_eq!(SmallValue::new(-1i32).to_sort_key(), zero.to_sort_key());
+    assert_eq!(
+        SmallValue::<i32>::from_sort_key(zero.to_sort_key()),
+        Ok(zero)
+    );
+    assert!(SmallValue::new(-2i32).to_sort_key() < zero.to_sort_key());
+    assert_eq!(
+        SmallValue::<i32>::from_sort_key((-1i32 ^ i32::MIN).to_be_bytes()),
+        Ok(zero.next_down().unwrap())
+    );
+}
+
+#[test]
+fn sort_key_t_3() {
+    let values = [
+        i128::MIN,
+        i128::MIN / 3,
+        -1_000_000_000_000,
+        -100,
+        -2,
+        0,
+        2,
+        100,
+        1_000_000_000_000,
+        i128::MAX / 3,
+        i128::MAX,
+    ];
+    let keys: Vec<[u8; 16]> = values
+        .iter()
+        .map(|value| SmallValue::new(*value).to_sort_key())
+        .collect();
+    assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    for (value, key) in values.iter().zip(&keys) {
+        assert_eq!(
+            SmallValue::<i128>::from_sort_key(*key),
+            Ok(SmallValue::new(*value))
+        );
+    }
+
+    let value = ScaledValue::<u64, 255>::new(u64::MAX);
+    assert_eq!(
+        ScaledValue::<u64, 255>::from_sort_key(value.to_sort_key()),
+        Ok(value)
+    );
+}
+
+#[test]
+fn sort_key_t_4() {
+    // `u8::MAX` is not an approximation
+    assert_eq!(
+        SmallValue::<u8>::from_sort_key([u8::MAX]),
+        Err(SmallValueError::NotRepresentable)
+    );
+    assert_eq!(
+        SmallValue::<i8>::from_sort_key([0]),
+        Err(SmallValueError::NotRepresentable)
+    );
+    // every approximation of a valid encoding has a key
+    let decoded: BTreeSet<u8> = (0..=u8::MAX)
+        .filter_map(|key| SmallValue::<u8>::from_sort_key([key]).ok())
+        .map(|value| value.approximate())
+        .collect();
+    let expected: BTreeSet<u8> = (1..=8)
+        .flat_map(|min_bits| (1..100).map(move |percent| (min_bits, percent)))
+        .map(|parts| SmallValue::<u8>::try_from(parts).unwrap().approximate())
+        .collect();
+    assert_eq!(decoded, expected);
+}
+
+#[test]
+fn sort_key_t_5() {
+    // the key is the approximate value with the sign bit flipped
+    let value = SmallValue::new(1_000_000u32);
+    assert_eq!(value.to_sort_key(), value.approximate().to_be_bytes());
+    let value = SmallValue::new(-1_000_000i64);
+    assert_eq!(
+        value.to_sort_key(),
+        (value.approximate() ^ i64::MIN).to_be_bytes()
+    );
+}
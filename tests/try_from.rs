@@ -0,0 +1,106 @@
+use approx_int::{SmallValue, SmallValueError};
+
+#[test]
+fn try_from_t_0() {
+    let small_value: Result<SmallValue<u32>, _> = (8, 50, false).try_into();
+    assert!(small_value.is_ok());
+}
+
+#[test]
+fn try_from_t_1() {
+    let small_value: Result<SmallValue<u32>, _> = (33, 50, false).try_into();
+    assert_eq!(
+        small_value,
+        Err(SmallValueError::MinBitsOutOfRange {
+            min_bits: 33,
+            min: 1,
+            max: 32
+        })
+    );
+}
+
+#[test]
+fn try_from_t_2() {
+    let small_value: Result<SmallValue<i32>, _> = (32, 50, false).try_into();
+    assert_eq!(
+        small_value,
+        Err(SmallValueError::MinBitsOutOfRange {
+            min_bits: 32,
+            min: 1,
+            max: 31
+        })
+    );
+}
+
+#[test]
+fn try_from_t_3() {
+    let small_value: Result<SmallValue<u64>, _> = (200, 250, true).try_into();
+    assert_eq!(small_value, Err(SmallValueError::NegativeUnsigned));
+}
+
+#[test]
+fn try_from_t_4() {
+    let small_value: Result<SmallValue<u64>, _> = (10, 100, false).try_into();
+    assert_eq!(
+        small_value,
+        Err(SmallValueError::PercentOutOfRange {
+            percent: 100,
+            min: 1,
+            max: 99
+        })
+    );
+}
+
+#[test]
+fn try_from_t_5() {
+    let small_value: Result<SmallValue<u64>, _> = (10, 0).try_into();
+    assert!(matches!(
+        small_value,
+        Err(SmallValueError::PercentOutOfRange { percent: 0, .. })
+    ));
+}
+
+#[test]
+fn try_from_t_6() {
+    // valid only for the encoding before `2.0`, see `from_legacy`
+    let small_value: Result<SmallValue<i64>, _> = (0, 199, true).try_into();
+    assert!(matches!(
+        small_value,
+        Err(SmallValueError::MinBitsOutOfRange { min_bits: 0, .. })
+    ));
+}
+
+#[test]
+fn try_from_t_7() {
+    let small_value: Result<SmallValue<i64>, _> = (0, 199, false).try_into();
+    assert!(matches!(
+        small_value,
+        Err(SmallValueError::MinBitsOutOfRange { min_bits: 0, .. })
+    ));
+}
+
+#[test]
+fn try_from_t_8() {
+    for value in -100_000..100_000 {
+        let small_value = SmallValue::new(value);
+        let tuple: (u8, u8, bool) = small_value.into();
+        assert!(small_value.is_valid());
+        assert_eq!(SmallValue::try_from(tuple), Ok(small_value));
+    }
+}
+
+#[test]
+fn try_from_t_9() {
+    for value in [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX] {
+        assert!(SmallValue::new(value).is_valid());
+    }
+    for value in [u128::MIN, u128::MAX] {
+        assert!(SmallValue::new(value).is_valid());
+    }
+}
+
+#[test]
+fn from_raw_unchecked_t_0() {
+    let small_value = SmallValue::<u32>::from_raw_unchecked(200, 250, true);
+    assert!(!small_value.is_valid());
+}
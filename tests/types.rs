@@ -0,0 +1,101 @@
+use approx_int::SmallValue;
+use std::num::{NonZeroI16, NonZeroI64, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+#[test]
+fn types_t_0() {
+    assert_eq!(SmallValue::new(u8::MAX).min_bits(), 8);
+    assert_eq!(SmallValue::new(u16::MAX).min_bits(), 16);
+    assert_eq!(SmallValue::new(usize::MAX).min_bits(), usize::BITS as u8);
+    assert_eq!(SmallValue::new(i8::MIN).min_bits(), 7);
+    assert_eq!(SmallValue::new(i16::MAX).min_bits(), 15);
+    assert_eq!(
+        SmallValue::new(isize::MAX).min_bits(),
+        isize::BITS as u8 - 1
+    );
+}
+
+#[test]
+fn types_t_1() {
+    // `100` is close to `i8::MAX`, the product `total * percent` does not fit in `i8`
+    assert_eq!(SmallValue::new(50i8).approximate(), 49);
+    assert_eq!(SmallValue::new(100i8).approximate(), 99);
+    for value in 2..=i8::MAX {
+        let approx = SmallValue::new(value).approximate();
+        assert!(0 < approx && approx < value);
+    }
+}
+
+#[test]
+fn types_t_2() {
+    for value in i8::MIN..0 {
+        let small_value = SmallValue::new(value);
+        assert!(small_value.is_valid());
+        assert!(small_value.approximate() <= 0);
+    }
+}
+
+#[test]
+fn types_t_3() {
+    for value in 2..=u8::MAX {
+        let approx = SmallValue::new(value).approximate();
+        assert!(0 < approx && approx < value);
+    }
+}
+
+#[test]
+fn types_t_4() {
+    for value in i16::MIN..=i16::MAX {
+        let small_value = SmallValue::new(value);
+        assert_eq!(
+            SmallValue::from_bits(small_value.to_bits()),
+            Ok(small_value)
+        );
+    }
+}
+
+#[test]
+fn types_t_5() {
+    for value in 2..=u16::MAX {
+        let approx = SmallValue::new(value).approximate();
+        assert!(0 < approx && approx < value);
+    }
+}
+
+#[test]
+fn non_zero_t_0() {
+    let id = NonZeroU64::new(8838183818381831838).unwrap();
+    let small_value: SmallValue<u64> = id.into();
+    assert_eq!(small_value, SmallValue::new(id.get()));
+    let approx: NonZeroU64 = small_value.into();
+    assert_eq!(approx.get(), small_value.approximate());
+}
+
+#[test]
+fn non_zero_t_1() {
+    let small_value: SmallValue<u8> = NonZeroU8::MIN.into();
+    assert_eq!(small_value.approximate(), 1);
+    assert_eq!(NonZeroU8::from(small_value), NonZeroU8::MIN);
+}
+
+#[test]
+fn non_zero_t_2() {
+    let small_value: SmallValue<i64> = NonZeroI64::new(-1).unwrap().into();
+    assert_eq!(small_value.approximate(), -1);
+    assert_eq!(NonZeroI64::from(small_value).get(), -1);
+}
+
+#[test]
+fn non_zero_t_3() {
+    for value in i16::MIN..=i16::MAX {
+        if let Some(number) = NonZeroI16::new(value) {
+            let approx = NonZeroI16::from(SmallValue::from(number));
+            assert_eq!(approx.get().signum(), value.signum());
+        }
+    }
+}
+
+#[test]
+fn non_zero_t_4() {
+    let small_value: SmallValue<usize> = NonZeroUsize::MAX.into();
+    assert_eq!(small_value.min_bits(), usize::BITS as u8);
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "alloc")]
+
+use approx_int::{ScaledValue, ScaledValueVec, SmallValue, SmallValueError, SmallValueVec};
+
+#[test]
+fn vec_t_0() {
+    let mut vec = SmallValueVec::new();
+    assert!(vec.is_empty());
+    for value in 0..1000u64 {
+        vec.push(value * 1_000_003);
+    }
+    assert_eq!(vec.len(), 1000);
+    assert_eq!(vec.as_bytes().len(), 2000);
+
+    for (index, value) in vec.iter().enumerate() {
+        let expected = SmallValue::new(index as u64 * 1_000_003);
+        assert_eq!(value, expected.approximate());
+        assert_eq!(vec.get(index), Some(expected.approximate()));
+        assert_eq!(vec.get_value(index), Some(expected));
+    }
+    assert_eq!(vec.get(1000), None);
+    assert_eq!(vec.get(usize::MAX), None);
+}
+
+#[test]
+fn vec_t_1() {
+    let values = [i128::MIN, -1_000_000, -1, 0, 1, 1_000_000, i128::MAX];
+    let vec: SmallValueVec<i128> = values.into_iter().collect();
+    let expected: Vec<i128> = values
+        .iter()
+        .map(|value| SmallValue::new(*value).approximate())
+        .collect();
+    assert_eq!(vec.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(
+        vec.iter().rev().collect::<Vec<_>>(),
+        expected.iter().rev().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(vec.iter().len(), values.len());
+
+    let copy = SmallValueVec::<i128>::from_bytes(vec.as_bytes()).unwrap();
+    assert_eq!(copy, vec);
+}
+
+#[test]
+fn vec_t_2() {
+    let mut vec: SmallValueVec<u32> = (0..10).map(|value| value * 1000).collect();
+    vec.extend([1, 2, 3]);
+    vec.extend([SmallValue::new(5000u32)]);
+    assert_eq!(vec.len(), 14);
+
+    let slice = vec.slice(2..5);
+    assert_eq!(slice.len(), 3);
+    assert_eq!(slice.get(0), vec.get(2));
+    assert_eq!(slice.get(3), None);
+    assert_eq!(
+        slice.iter().collect::<Vec<_>>(),
+        vec.iter().skip(2).take(3).collect::<Vec<_>>()
+    );
+    assert_eq!(slice.as_bytes(), &vec.as_bytes()[4..10]);
+    assert_eq!(slice.slice(1..).get(0), vec.get(3));
+    assert_eq!(vec.slice(..=1).len(), 2);
+    let expected = [1, 2, 3]
+        .map(|value| SmallValue::new(value).approximate())
+        .to_vec();
+    assert_eq!(
+        vec.slice(10..13).to_vec().iter().collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(
+        vec.slice(13..).get(0),
+        Some(SmallValue::new(5000u32).approximate())
+    );
+    assert!(vec.slice(14..).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn vec_t_3() {
+    let vec: SmallValueVec<u32> = (0..10).collect();
+    vec.slice(5..11);
+}
+
+#[test]
+fn vec_t_4() {
+    let mut vec: SmallValueVec<i16> = [100, -200, 300].into_iter().collect();
+    assert_eq!(vec.pop(), Some(SmallValue::new(300i16).approximate()));
+    vec.set(0, -5000);
+    assert_eq!(vec.get(0), Some(SmallValue::new(-5000i16).approximate()));
+    vec.truncate(1);
+    assert_eq!(vec.len(), 1);
+    vec.clear();
+    assert_eq!(vec.pop(), None);
+    assert_eq!(vec, SmallValueVec::default());
+}
+
+#[test]
+fn vec_t_5() {
+    assert_eq!(
+        SmallValueVec::<u8>::from_bytes(&[1, 2, 3]),
+        Err(SmallValueError::InvalidByteLength { len: 3 })
+    );
+    // the sign bit is not valid for an unsigned type
+    let bytes = SmallValue::new(-100i8).to_le_bytes();
+    assert!(SmallValueVec::<i8>::from_bytes(&bytes).is_ok());
+    assert!(SmallValueVec::<u16>::from_bytes(&bytes).is_err());
+
+    // non-canonical encodings are normalized
+    let value = SmallValue::<u32>::try_from((8, 1)).unwrap();
+    let vec = SmallValueVec::<u32>::from_bytes(&value.to_le_bytes()).unwrap();
+    assert_eq!(vec.get_value(0), Some(value.normalize()));
+    assert_eq!(vec.get(0), Some(value.approximate()));
+}
+
+#[test]
+fn vec_t_6() {
+    let values: Vec<ScaledValue<u64, 255>> = (1..100u64)
+        .map(|value| ScaledValue::new(value << 40))
+        .collect();
+    let vec = ScaledValueVec::from(values.as_slice());
+    assert_eq!(
+        vec.iter().collect::<Vec<_>>(),
+        values
+            .iter()
+            .map(|value| value.approximate())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!((&vec).into_iter().count(), 99);
+    assert_eq!(vec.as_slice().into_iter().count(), 99);
+}